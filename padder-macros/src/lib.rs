@@ -0,0 +1,43 @@
+//!
+//! Procedural macros for the [padder](https://crates.io/crates/padder) crate.
+//!
+//! This crate is not intended to be used directly, enable the `derive` or `macros` feature of padder
+//! instead and the macros will be re-exported from there.
+//!
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+mod pad;
+mod record;
+
+/// Derives `padder::PadRecord` for a struct, rendering it into a fixed-width line.
+///
+/// Every field has to be annotated with a `#[pad(...)]` attribute describing its field:
+/// - `width = <usize>`: the width (in utf8 chars) of the field, at least 1 (required).
+/// - `align = "left" | "right" | "center" | "start" | "end"`: the alignment mode (defaults to `Alignment::default()`).
+/// - `symbol = <char>`: the padding symbol (defaults to `' '`).
+///
+/// Fields can be excluded from the rendered line with `#[pad(skip)]`.
+///
+/// The value of each field is rendered through its [`std::fmt::Display`] implementation.
+#[proc_macro_derive(PadRecord, attributes(pad))]
+pub fn derive_pad_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    record::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Pads each argument according to its placeholder in the format string and concatenates the
+/// results into a single preallocated `String`.
+///
+/// Every placeholder has to be written as `{:[fill]align width}` (where `align` is one of `<`,
+/// `^`, or `>`), which is validated at compile time. Each argument has to implement `AsRef<str>`.
+#[proc_macro]
+pub fn pad(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as pad::PadInput);
+    pad::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,195 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, LitStr, Token};
+
+/// The input of the `pad!` macro: a format string followed by its arguments.
+pub(crate) struct PadInput {
+    format: LitStr,
+    args: Vec<Expr>,
+}
+
+impl Parse for PadInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let format: LitStr = input.parse()?;
+        let mut args: Vec<Expr> = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            args = Punctuated::<Expr, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect();
+        }
+        Ok(Self { format, args })
+    }
+}
+
+/// A single piece of the parsed format string.
+enum Piece {
+    Literal(String),
+    Field {
+        fill: char,
+        align: TokenStream,
+        width: usize,
+    },
+}
+
+pub(crate) fn expand(input: PadInput) -> syn::Result<TokenStream> {
+    let pieces = parse_format(&input.format)?;
+
+    let n_fields: usize = pieces
+        .iter()
+        .filter(|piece| matches!(piece, Piece::Field { .. }))
+        .count();
+    if n_fields != input.args.len() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "{} placeholder(s) in the format string but {} argument(s) were given",
+                n_fields,
+                input.args.len()
+            ),
+        ));
+    }
+
+    let idents: Vec<_> = (0..n_fields)
+        .map(|idx| format_ident!("arg{}", idx))
+        .collect();
+    let args = &input.args;
+
+    let mut capacity = Vec::new();
+    let mut writes = Vec::new();
+    let mut idx: usize = 0;
+    for piece in &pieces {
+        match piece {
+            Piece::Literal(literal) => {
+                let n_bytes: usize = literal.len();
+                capacity.push(quote! { #n_bytes });
+                writes.push(quote! { buffer.push_str(#literal); });
+            }
+            Piece::Field { fill, align, width } => {
+                let ident = &idents[idx];
+                capacity.push(quote! { #ident.len() + #width * #fill.len_utf8() });
+                writes.push(quote! {
+                    ::padder::Source::pad_to_buffer(&#ident, #width, #align, #fill, &mut buffer);
+                });
+                idx += 1;
+            }
+        }
+    }
+
+    // A format string without any pieces never writes to the buffer, which must then not be
+    // declared `mut` to not trip `unused_mut` in the calling crate.
+    let binding = match writes.is_empty() {
+        true => quote! { buffer },
+        false => quote! { mut buffer },
+    };
+    Ok(quote! {
+        match (#(&(#args),)*) {
+            (#(#idents,)*) => {
+                #(let #idents: &str = ::core::convert::AsRef::<str>::as_ref(#idents);)*
+                let #binding = ::std::string::String::with_capacity(0 #(+ #capacity)*);
+                #(#writes)*
+                buffer
+            }
+        }
+    })
+}
+
+/// Parses the format string into its literal pieces and padded fields.
+///
+/// Every placeholder has to be written as `{:[fill]align width}` where `align` is one of
+/// `<`, `^`, or `>`. Literal braces are escaped as `{{` and `}}`.
+fn parse_format(format: &LitStr) -> syn::Result<Vec<Piece>> {
+    let value = format.value();
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut literal = String::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut spec = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => spec.push(c),
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                format,
+                                "unterminated placeholder, expected `}`",
+                            ));
+                        }
+                    }
+                }
+
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(parse_spec(format, &spec)?);
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(
+                    format,
+                    "unmatched `}` in format string, use `}}` to escape it",
+                ));
+            }
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+
+    Ok(pieces)
+}
+
+/// Parses the contents of a single placeholder, e.g., `:*^10`.
+fn parse_spec(format: &LitStr, spec: &str) -> syn::Result<Piece> {
+    let error = |message: &str| {
+        syn::Error::new_spanned(
+            format,
+            format!("invalid placeholder `{{{spec}}}`: {message}"),
+        )
+    };
+
+    let Some(spec) = spec.strip_prefix(':') else {
+        return Err(error("expected `{:[fill]align width}`"));
+    };
+
+    let as_align = |c: char| match c {
+        '<' => Some(quote! { ::padder::Alignment::Left }),
+        '^' => Some(quote! { ::padder::Alignment::Center }),
+        '>' => Some(quote! { ::padder::Alignment::Right }),
+        _ => None,
+    };
+
+    let mut chars = spec.chars();
+    let first = chars.next();
+    let second = chars.next();
+    let (fill, align, width) = match (first, second.and_then(as_align)) {
+        (Some(fill), Some(align)) => (fill, align, &spec[fill.len_utf8() + 1..]),
+        _ => match first.and_then(as_align) {
+            Some(align) => (' ', align, &spec[1..]),
+            None => return Err(error("missing alignment, expected one of `<`, `^`, or `>`")),
+        },
+    };
+
+    if width.is_empty() {
+        return Err(error("missing width"));
+    }
+    let width: usize = match width.parse() {
+        Ok(width) if width > 0 => width,
+        _ => return Err(error("width has to be a positive integer")),
+    };
+
+    Ok(Piece::Field { fill, align, width })
+}
@@ -0,0 +1,141 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Index, LitChar, LitInt, LitStr, Member};
+
+/// The parsed `#[pad(...)]` attribute of a single struct field.
+struct FieldSpec {
+    member: Member,
+    width: usize,
+    align: TokenStream,
+    symbol: char,
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "PadRecord can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut specs: Vec<FieldSpec> = Vec::new();
+    for (idx, field) in fields.iter().enumerate() {
+        if let Some(spec) = parse_field(idx, field)? {
+            specs.push(spec);
+        }
+    }
+
+    let width: usize = specs.iter().map(|spec| spec.width).sum();
+    let writes = specs.iter().map(|spec| {
+        let FieldSpec {
+            member,
+            width,
+            align,
+            symbol,
+        } = spec;
+        quote! {
+            value.clear();
+            ::std::fmt::Write::write_fmt(&mut value, format_args!("{}", &self.#member))
+                .expect("a Display implementation returned an error unexpectedly");
+            ::padder::Source::pad_to_buffer(&value.as_str(), #width, #align, #symbol, buffer);
+        }
+    });
+
+    // Records where every field is skipped render nothing, so neither the buffer nor the
+    // intermediate value are used.
+    let body = if specs.is_empty() {
+        quote! {
+            fn pad_record_to_buffer(&self, _buffer: &mut ::std::string::String) {}
+        }
+    } else {
+        quote! {
+            fn pad_record_to_buffer(&self, buffer: &mut ::std::string::String) {
+                let mut value = ::std::string::String::new();
+                #(#writes)*
+            }
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::padder::PadRecord for #name #ty_generics #where_clause {
+            const WIDTH: usize = #width;
+
+            #body
+        }
+    })
+}
+
+/// Parses the `#[pad(...)]` attribute of the field, returns `None` if the field is skipped.
+fn parse_field(idx: usize, field: &Field) -> syn::Result<Option<FieldSpec>> {
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(idx)),
+    };
+
+    let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("pad")) else {
+        return Err(syn::Error::new_spanned(
+            field,
+            "missing `#[pad(width = ...)]` attribute, use `#[pad(skip)]` to exclude the field",
+        ));
+    };
+
+    let mut skip = false;
+    let mut width: Option<usize> = None;
+    let mut align = quote! { ::padder::Alignment::default() };
+    let mut symbol = ' ';
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("skip") {
+            skip = true;
+        } else if meta.path.is_ident("width") {
+            let lit: LitInt = meta.value()?.parse()?;
+            match lit.base10_parse::<usize>()? {
+                0 => return Err(syn::Error::new_spanned(lit, "width has to be at least 1")),
+                n => width = Some(n),
+            }
+        } else if meta.path.is_ident("align") {
+            let lit: LitStr = meta.value()?.parse()?;
+            align = match lit.value().as_str() {
+                "left" => quote! { ::padder::Alignment::Left },
+                "right" => quote! { ::padder::Alignment::Right },
+                "center" => quote! { ::padder::Alignment::Center },
+                "start" => quote! { ::padder::Alignment::Start },
+                "end" => quote! { ::padder::Alignment::End },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "expected one of \"left\", \"right\", \"center\", \"start\", or \"end\"",
+                    ));
+                }
+            };
+        } else if meta.path.is_ident("symbol") {
+            symbol = meta.value()?.parse::<LitChar>()?.value();
+        } else {
+            return Err(meta.error("expected one of `width`, `align`, `symbol`, or `skip`"));
+        }
+        Ok(())
+    })?;
+
+    if skip {
+        return Ok(None);
+    }
+
+    match width {
+        Some(width) => Ok(Some(FieldSpec {
+            member,
+            width,
+            align,
+            symbol,
+        })),
+        None => Err(syn::Error::new_spanned(
+            attr,
+            "missing `width = ...` in `#[pad(...)]` attribute",
+        )),
+    }
+}
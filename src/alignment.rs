@@ -1,5 +1,11 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::bidi::Direction;
+
 /// Represents padding as a pair of `(left, right)` counts.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pads(pub usize, pub usize);
 
 impl Pads {
@@ -19,6 +25,14 @@ impl Pads {
 /// - `Left`: aligns content to the left, padding goes to the right.
 /// - `Right`: aligns content to the right, padding goes to the left.
 /// - `Center`: distributes padding equally on both sides (extra symbol goes to the right of number of symbols to padd is odd).
+/// - `Start`: aligns content to the logical start of the text, which is the left for
+///   left-to-right text and the right for right-to-left text, see [`Alignment::physical`].
+///   Truncation always keeps the logical start.
+/// - `End`: aligns content to the logical end of the text. Truncation always keeps the logical
+///   end.
+///
+/// Without a [`Direction`] (see [`crate::BidiSource`]), `Start` and `End` behave like `Left`
+/// and `Right` respectively.
 ///
 /// # Examples
 /// ```
@@ -33,12 +47,26 @@ impl Pads {
 /// let c = Alignment::Center;
 /// assert_eq!(Pads(5, 6), c.pads(11));
 /// ```
+///
+/// An alignment can also be parsed from (and formatted as) a string, where `"left"` or `"<"`,
+/// `"right"` or `">"`, `"center"` or `"^"`, `"start"`, and `"end"` are accepted
+/// (case-insensitive). With the
+/// `serde` feature enabled the same representations are used when (de)serializing.
+/// ```
+/// use padder::Alignment;
+///
+/// assert_eq!(Ok(Alignment::Left), "<".parse());
+/// assert_eq!(Ok(Alignment::Center), "Center".parse());
+/// assert_eq!("right", Alignment::Right.to_string());
+/// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum Alignment {
     Left,
     #[default]
     Right,
     Center,
+    Start,
+    End,
 }
 
 impl Alignment {
@@ -48,13 +76,100 @@ impl Alignment {
     /// See [`Alignment`] for more details.
     pub fn pads(&self, n: usize) -> Pads {
         match self {
-            Self::Left => Pads(0, n),
-            Self::Right => Pads(n, 0),
+            Self::Left | Self::Start => Pads(0, n),
+            Self::Right | Self::End => Pads(n, 0),
             Self::Center => Pads(n / 2, n - n / 2),
         }
     }
+
+    /// Maps the logical `Start` and `End` alignments to the physical `Left` or `Right`
+    /// alignment for the text `s` written in the `direction`, other alignments are returned
+    /// as-is.
+    ///
+    /// [`Direction::Auto`] is resolved against `s` first, see [`Direction::resolve`].
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// assert_eq!(Alignment::Left, Alignment::Start.physical(Direction::Ltr, "gael"));
+    /// assert_eq!(Alignment::Right, Alignment::Start.physical(Direction::Rtl, "gael"));
+    /// assert_eq!(Alignment::Left, Alignment::End.physical(Direction::Rtl, "gael"));
+    /// assert_eq!(Alignment::Right, Alignment::Start.physical(Direction::Auto, "שלום"));
+    /// assert_eq!(Alignment::Center, Alignment::Center.physical(Direction::Rtl, "gael"));
+    /// ```
+    pub fn physical(&self, direction: Direction, s: &str) -> Alignment {
+        match (self, direction.resolve(s)) {
+            (Self::Start, Direction::Rtl) => Self::Right,
+            (Self::End, Direction::Rtl) => Self::Left,
+            (Self::Start, _) => Self::Left,
+            (Self::End, _) => Self::Right,
+            (mode, _) => *mode,
+        }
+    }
 }
 
+impl FromStr for Alignment {
+    type Err = ParseAlignmentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "<" => Ok(Self::Left),
+            ">" => Ok(Self::Right),
+            "^" => Ok(Self::Center),
+            _ if s.eq_ignore_ascii_case("left") => Ok(Self::Left),
+            _ if s.eq_ignore_ascii_case("right") => Ok(Self::Right),
+            _ if s.eq_ignore_ascii_case("center") => Ok(Self::Center),
+            _ if s.eq_ignore_ascii_case("start") => Ok(Self::Start),
+            _ if s.eq_ignore_ascii_case("end") => Ok(Self::End),
+            _ => Err(ParseAlignmentError(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Alignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => f.write_str("left"),
+            Self::Right => f.write_str("right"),
+            Self::Center => f.write_str("center"),
+            Self::Start => f.write_str("start"),
+            Self::End => f.write_str("end"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Alignment {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Alignment {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The error returned when a string could not be parsed as an [`Alignment`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseAlignmentError(String);
+
+impl fmt::Display for ParseAlignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid alignment '{}', expected one of 'left', 'right', 'center', 'start', 'end', '<', '>', or '^'",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseAlignmentError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,4 +202,69 @@ mod tests {
         assert_eq!(expected.left(), p.left());
         assert_eq!(0, p.right());
     }
+
+    #[test]
+    fn alignment_from_str() {
+        assert_eq!(Ok(Alignment::Left), "left".parse());
+        assert_eq!(Ok(Alignment::Left), "<".parse());
+        assert_eq!(Ok(Alignment::Right), "RIGHT".parse());
+        assert_eq!(Ok(Alignment::Right), ">".parse());
+        assert_eq!(Ok(Alignment::Center), "center".parse());
+        assert_eq!(Ok(Alignment::Center), "^".parse());
+        assert_eq!(Ok(Alignment::Start), "Start".parse());
+        assert_eq!(Ok(Alignment::End), "end".parse());
+        assert_eq!(
+            Err(ParseAlignmentError(String::from("middle"))),
+            "middle".parse::<Alignment>()
+        );
+    }
+
+    #[test]
+    fn alignment_display_roundtrip() {
+        for a in [
+            Alignment::Left,
+            Alignment::Right,
+            Alignment::Center,
+            Alignment::Start,
+            Alignment::End,
+        ] {
+            assert_eq!(Ok(a), a.to_string().parse());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_serde {
+    use super::*;
+
+    #[test]
+    fn alignment_serialize() {
+        assert_eq!(
+            "\"center\"",
+            serde_json::to_string(&Alignment::Center).unwrap()
+        );
+    }
+
+    #[test]
+    fn alignment_deserialize() {
+        let a: Vec<Alignment> = serde_json::from_str(r#"["left", ">", "^", "Right"]"#).unwrap();
+        assert_eq!(
+            vec![
+                Alignment::Left,
+                Alignment::Right,
+                Alignment::Center,
+                Alignment::Right
+            ],
+            a
+        );
+        assert!(serde_json::from_str::<Alignment>(r#""up""#).is_err());
+    }
+
+    #[test]
+    fn pads_roundtrip() {
+        let p = Pads(3, 4);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!("[3,4]", json);
+        assert_eq!(p, serde_json::from_str(&json).unwrap());
+    }
 }
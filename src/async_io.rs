@@ -0,0 +1,328 @@
+use std::future::Future;
+use std::io;
+
+use crate::alignment::{Alignment, Pads};
+use crate::source::Source;
+use crate::width::Width;
+
+/// The number of bytes of pad symbols written to the writer at once.
+const N_BYTES_CHUNK: usize = 64;
+
+/// The padded content split into the runs which are written to an async writer.
+struct Runs<'a> {
+    pads: Pads,
+    content: &'a [u8],
+    symbol: [u8; 4],
+    symbol_len: usize,
+}
+
+impl<'a> Runs<'a> {
+    /// Splits the (possibly truncated) `s` and its pads.
+    fn from_str(s: &'a str, width: impl Into<Width>, mode: Alignment, symbol: char) -> Self {
+        let n_chars_original: usize = s.chars().count();
+        let width: usize = width.into().resolve(n_chars_original);
+        let (content, pads) = if width < n_chars_original {
+            (s.truncate_to_fit(width, mode), Pads(0, 0))
+        } else {
+            (s, mode.pads(width - n_chars_original))
+        };
+
+        let mut encoded = [0u8; 4];
+        let symbol_len: usize = symbol.encode_utf8(&mut encoded).len();
+        Self {
+            pads,
+            content: content.as_bytes(),
+            symbol: encoded,
+            symbol_len,
+        }
+    }
+
+    /// Splits the (possibly truncated) `s` and its pads.
+    fn from_bytes(s: &'a [u8], width: impl Into<Width>, mode: Alignment, symbol: u8) -> Self {
+        let width: usize = width.into().resolve(s.len());
+        let (content, pads) = if width < s.len() {
+            (s.truncate_to_fit(width, mode), Pads(0, 0))
+        } else {
+            (s, mode.pads(width - s.len()))
+        };
+
+        Self {
+            pads,
+            content,
+            symbol: [symbol, 0, 0, 0],
+            symbol_len: 1,
+        }
+    }
+
+    /// Returns a chunk filled with as many whole pad symbols as fit.
+    fn chunk(&self) -> ([u8; N_BYTES_CHUNK], usize) {
+        let mut chunk = [0u8; N_BYTES_CHUNK];
+        let n_symbols: usize = N_BYTES_CHUNK / self.symbol_len;
+        chunk
+            .chunks_exact_mut(self.symbol_len)
+            .for_each(|c| c.copy_from_slice(&self.symbol[..self.symbol_len]));
+        (chunk, n_symbols)
+    }
+}
+
+/// Writes `n` pad symbols in chunks to the `writer` using the `write_all` method in scope.
+macro_rules! write_run {
+    ($runs:expr, $n:expr, $writer:ident) => {{
+        let (chunk, n_symbols_chunk) = $runs.chunk();
+        let mut n_symbols: usize = $n;
+        while n_symbols > 0 {
+            let n: usize = n_symbols.min(n_symbols_chunk);
+            $writer.write_all(&chunk[..n * $runs.symbol_len]).await?;
+            n_symbols -= n;
+        }
+    }};
+}
+
+#[cfg(feature = "tokio")]
+impl Runs<'_> {
+    async fn write_tokio<W>(self, writer: &mut W) -> io::Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin + Send + ?Sized,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        write_run!(self, self.pads.left(), writer);
+        writer.write_all(self.content).await?;
+        write_run!(self, self.pads.right(), writer);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl Runs<'_> {
+    async fn write_futures<W>(self, writer: &mut W) -> io::Result<()>
+    where
+        W: futures_io::AsyncWrite + Unpin + Send + ?Sized,
+    {
+        use futures_util::io::AsyncWriteExt;
+
+        write_run!(self, self.pads.left(), writer);
+        writer.write_all(self.content).await?;
+        write_run!(self, self.pads.right(), writer);
+        Ok(())
+    }
+}
+
+/// A companion trait to [`Source`] for writing the padded content straight to an async
+/// writer, without building the whole padded buffer in memory.
+///
+/// The content is written as-is, and the pad symbols are written in small chunks.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "tokio")]
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use padder::*;
+/// use tokio::io::AsyncReadExt;
+///
+/// let (mut client, mut server) = tokio::io::duplex(64);
+/// "firelink".pad_to_async_writer(12, Alignment::Left, '.', &mut client).await?;
+/// drop(client);
+///
+/// let mut line = String::new();
+/// server.read_to_string(&mut line).await?;
+/// assert_eq!("firelink....", line);
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+pub trait AsyncPadSource: Source + Sync {
+    /// Pads (or truncates) to the specified `width` using the given `symbol` according to the
+    /// specified alignment `mode`, writing the result to the tokio `writer`.
+    #[cfg(feature = "tokio")]
+    fn pad_to_async_writer<W>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        writer: &mut W,
+    ) -> impl Future<Output = io::Result<()>> + Send
+    where
+        W: tokio::io::AsyncWrite + Unpin + Send + ?Sized;
+
+    /// Pads (or truncates) to the specified `width` using the given `symbol` according to the
+    /// specified alignment `mode`, writing the result to the futures-io `writer`.
+    #[cfg(feature = "futures-io")]
+    fn pad_to_futures_writer<W>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        writer: &mut W,
+    ) -> impl Future<Output = io::Result<()>> + Send
+    where
+        W: futures_io::AsyncWrite + Unpin + Send + ?Sized;
+}
+
+/// Implements [`AsyncPadSource`] for a source type, splitting it into [`Runs`] using the
+/// specified constructor.
+macro_rules! impl_async_pad_source {
+    ($ty:ty, $runs:path) => {
+        impl AsyncPadSource for $ty {
+            #[cfg(feature = "tokio")]
+            fn pad_to_async_writer<W>(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: Self::Symbol,
+                writer: &mut W,
+            ) -> impl Future<Output = io::Result<()>> + Send
+            where
+                W: tokio::io::AsyncWrite + Unpin + Send + ?Sized,
+            {
+                $runs(self, width, mode, symbol).write_tokio(writer)
+            }
+
+            #[cfg(feature = "futures-io")]
+            fn pad_to_futures_writer<W>(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: Self::Symbol,
+                writer: &mut W,
+            ) -> impl Future<Output = io::Result<()>> + Send
+            where
+                W: futures_io::AsyncWrite + Unpin + Send + ?Sized,
+            {
+                $runs(self, width, mode, symbol).write_futures(writer)
+            }
+        }
+    };
+}
+
+impl_async_pad_source!(&str, Runs::from_str);
+impl_async_pad_source!(String, Runs::from_str);
+impl_async_pad_source!(&[u8], Runs::from_bytes);
+impl_async_pad_source!(Vec<u8>, Runs::from_bytes);
+
+/// A [`tokio_util::codec::Encoder`] which pads (or truncates) each outgoing frame to a fixed
+/// width, optionally followed by a terminator such as `"\n"`.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "tokio")]
+/// # {
+/// use padder::*;
+/// use bytes::BytesMut;
+/// use tokio_util::codec::Encoder;
+///
+/// let mut encoder = PadEncoder::new(6, Alignment::Right, '0').with_terminator("\n");
+/// let mut frames = BytesMut::new();
+/// encoder.encode("42", &mut frames).unwrap();
+/// encoder.encode("1337", &mut frames).unwrap();
+/// assert_eq!(&b"000042\n001337\n"[..], &frames[..]);
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PadEncoder {
+    width: Width,
+    mode: Alignment,
+    symbol: char,
+    terminator: &'static str,
+}
+
+#[cfg(feature = "tokio")]
+impl PadEncoder {
+    /// Creates a new encoder padding each frame to the `width` using the given `symbol`
+    /// according to the specified alignment `mode`.
+    pub fn new(width: impl Into<Width>, mode: Alignment, symbol: char) -> Self {
+        Self {
+            width: width.into(),
+            mode,
+            symbol,
+            terminator: "",
+        }
+    }
+
+    /// Appends the `terminator` to every padded frame.
+    pub fn with_terminator(mut self, terminator: &'static str) -> Self {
+        self.terminator = terminator;
+        self
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<S: AsRef<str>> tokio_util::codec::Encoder<S> for PadEncoder {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: S, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        use crate::sink::SinkSource;
+
+        item.as_ref()
+            .pad_to_sink(self.width, self.mode, self.symbol, dst);
+        dst.extend_from_slice(self.terminator.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests_tokio {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn str_to_duplex() {
+        let (mut client, mut server) = tokio::io::duplex(16);
+        let writer = tokio::spawn(async move {
+            "ツ"
+                .pad_to_async_writer(100, Alignment::Center, '🐉', &mut client)
+                .await
+                .unwrap();
+        });
+
+        let mut output = String::new();
+        server.read_to_string(&mut output).await.unwrap();
+        writer.await.unwrap();
+        assert_eq!("ツ".pad(100, Alignment::Center, '🐉'), output);
+    }
+
+    #[tokio::test]
+    async fn bytes_truncated() {
+        let mut output: Vec<u8> = Vec::new();
+        let v: Vec<u8> = vec![1, 2, 3, 4];
+        v.pad_to_async_writer(2, Alignment::Right, 0, &mut output)
+            .await
+            .unwrap();
+        b"ab"
+            .as_slice()
+            .pad_to_async_writer(Width::AtLeast(3), Alignment::Left, b' ', &mut output)
+            .await
+            .unwrap();
+        assert_eq!(b"\x03\x04ab ", output.as_slice());
+    }
+
+    #[test]
+    fn encoder_truncates() {
+        use tokio_util::codec::Encoder;
+
+        let mut encoder = PadEncoder::new(3, Alignment::Left, ' ').with_terminator("\r\n");
+        let mut dst = bytes::BytesMut::new();
+        encoder.encode(String::from("oceiros"), &mut dst).unwrap();
+        assert_eq!(&b"oce\r\n"[..], &dst[..]);
+    }
+}
+
+#[cfg(all(test, feature = "futures-io"))]
+mod tests_futures_io {
+    use super::*;
+
+    #[test]
+    fn str_to_futures_writer() {
+        let mut output = futures_util::io::Cursor::new(Vec::new());
+        futures_executor::block_on(async {
+            String::from("ludleth")
+                .pad_to_futures_writer(80, Alignment::Right, '-', &mut output)
+                .await
+                .unwrap();
+        });
+        assert_eq!(
+            "ludleth".pad(80, Alignment::Right, '-').as_bytes(),
+            output.get_ref().as_slice()
+        );
+    }
+}
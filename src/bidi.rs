@@ -0,0 +1,230 @@
+use crate::alignment::Alignment;
+use crate::mutable_source::MutableSource;
+use crate::source::Source;
+use crate::width::Width;
+
+/// The writing direction of text, used to map the logical [`Alignment::Start`] and
+/// [`Alignment::End`] alignments to physical padding.
+///
+/// - `Ltr`: left-to-right text, e.g., English.
+/// - `Rtl`: right-to-left text, e.g., Arabic or Hebrew.
+/// - `Auto`: detected from the first strong character of the text, see [`Direction::resolve`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+    Auto,
+}
+
+impl Direction {
+    /// Resolves [`Direction::Auto`] to the direction of the first strong character of the `s`,
+    /// falling back to [`Direction::Ltr`] if the `s` contains no strong characters (e.g., only
+    /// digits, punctuation, or whitespace). Other directions are returned as-is.
+    ///
+    /// Characters of right-to-left scripts (Hebrew, Arabic, Syriac, Thaana, N'Ko, ...) and the
+    /// RLM/ALM marks are strong right-to-left, all other alphabetic characters and the LRM mark
+    /// are strong left-to-right.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// assert_eq!(Direction::Rtl, Direction::Auto.resolve("42 שלום world"));
+    /// assert_eq!(Direction::Ltr, Direction::Auto.resolve("42 world שלום"));
+    /// assert_eq!(Direction::Ltr, Direction::Auto.resolve("1984"));
+    /// ```
+    pub fn resolve(&self, s: &str) -> Direction {
+        match self {
+            Self::Auto => s
+                .chars()
+                .find_map(strong_direction)
+                .unwrap_or(Direction::Ltr),
+            direction => *direction,
+        }
+    }
+}
+
+/// Returns the direction of the `c` if it is a strong character.
+fn strong_direction(c: char) -> Option<Direction> {
+    match c {
+        '\u{200F}' | '\u{061C}' => Some(Direction::Rtl),
+        '\u{200E}' => Some(Direction::Ltr),
+        '\u{0590}'..='\u{08FF}'
+        | '\u{FB1D}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}'
+        | '\u{10800}'..='\u{10FFF}'
+        | '\u{1E800}'..='\u{1EFFF}'
+            if c.is_alphabetic() =>
+        {
+            Some(Direction::Rtl)
+        }
+        _ if c.is_alphabetic() => Some(Direction::Ltr),
+        _ => None,
+    }
+}
+
+/// Returns the alignment mode to apply to the `s` when padding (or truncating) it to the `width`.
+///
+/// Padding is placed according to the physical alignment in the `direction`, while truncation
+/// uses the logical alignment such that, e.g., [`Alignment::Start`] keeps the logical start.
+fn bidi_mode(s: &str, width: usize, mode: Alignment, direction: Direction) -> Alignment {
+    if width < s.chars().count() {
+        mode
+    } else {
+        mode.physical(direction, s)
+    }
+}
+
+/// A companion trait to [`Source`] for string types which pads logical alignments according to
+/// the writing direction of the text.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// // Right-to-left text is aligned to the right ...
+/// assert_eq!("····שלום", "שלום".pad_bidi(8, Alignment::Start, '·', Direction::Auto));
+/// assert_eq!("solaire·", "solaire".pad_bidi(8, Alignment::Start, '·', Direction::Auto));
+///
+/// // ... but truncation keeps the logical start of the text.
+/// assert_eq!("של", "שלום".pad_bidi(2, Alignment::Start, '·', Direction::Rtl));
+/// ```
+pub trait BidiSource: Source {
+    /// Pads (or truncates) like [`Source::pad`], mapping [`Alignment::Start`] and
+    /// [`Alignment::End`] to physical padding in the `direction`.
+    fn pad_bidi(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        direction: Direction,
+    ) -> Self::Output;
+
+    /// Pads (or truncates) into the `buffer` like [`Source::pad_to_buffer`], mapping
+    /// [`Alignment::Start`] and [`Alignment::End`] to physical padding in the `direction`.
+    fn pad_to_buffer_bidi(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        direction: Direction,
+        buffer: &mut Self::Buffer,
+    );
+}
+
+impl<S: Source + AsRef<str> + ?Sized> BidiSource for S {
+    fn pad_bidi(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        direction: Direction,
+    ) -> Self::Output {
+        let s: &str = self.as_ref();
+        let width: usize = width.into().resolve(s.chars().count());
+        self.pad(width, bidi_mode(s, width, mode, direction), symbol)
+    }
+
+    fn pad_to_buffer_bidi(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        direction: Direction,
+        buffer: &mut Self::Buffer,
+    ) {
+        let s: &str = self.as_ref();
+        let width: usize = width.into().resolve(s.chars().count());
+        self.pad_to_buffer(width, bidi_mode(s, width, mode, direction), symbol, buffer);
+    }
+}
+
+/// A companion trait to [`MutableSource`] for string types which pads logical alignments
+/// in-place according to the writing direction of the text, see [`BidiSource`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut s = String::from("مرحبا");
+/// (&mut s).pad_bidi(7, Alignment::End, '-', Direction::Auto);
+/// assert_eq!("مرحبا--", s);
+/// ```
+pub trait MutableBidiSource: MutableSource {
+    /// Pads (or truncates) in-place like [`MutableSource::pad`], mapping [`Alignment::Start`]
+    /// and [`Alignment::End`] to physical padding in the `direction`.
+    fn pad_bidi(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        direction: Direction,
+    );
+}
+
+impl<S: MutableSource + AsRef<str>> MutableBidiSource for S {
+    fn pad_bidi(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        direction: Direction,
+    ) {
+        let s: &str = self.as_ref();
+        let width: usize = width.into().resolve(s.chars().count());
+        let mode: Alignment = bidi_mode(s, width, mode, direction);
+        MutableSource::pad(self, width, mode, symbol);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_skips_weak_chars() {
+        assert_eq!(Direction::Rtl, Direction::Auto.resolve("  (123) مرحبا"));
+        assert_eq!(Direction::Ltr, Direction::Auto.resolve("... ツ"));
+        assert_eq!(Direction::Rtl, Direction::Auto.resolve("\u{200F}abc"));
+        assert_eq!(Direction::Ltr, Direction::Ltr.resolve("שלום"));
+    }
+
+    #[test]
+    fn start_end_without_direction() {
+        assert_eq!("gael--", "gael".pad(6, Alignment::Start, '-'));
+        assert_eq!("--gael", "gael".pad(6, Alignment::End, '-'));
+        assert_eq!("ga", "gael".pad(2, Alignment::Start, '-'));
+        assert_eq!("el", "gael".pad(2, Alignment::End, '-'));
+    }
+
+    #[test]
+    fn rtl_end_truncates_logical_end() {
+        let s = String::from("שלום");
+        assert_eq!("שלום**", s.pad_bidi(6, Alignment::End, '*', Direction::Rtl));
+        assert_eq!("ום", s.pad_bidi(2, Alignment::End, '*', Direction::Rtl));
+    }
+
+    #[test]
+    fn pad_to_buffer_bidi() {
+        let mut buffer = String::from("|");
+        "שלום".pad_to_buffer_bidi(
+            Width::AtLeast(5),
+            Alignment::Start,
+            ' ',
+            Direction::Auto,
+            &mut buffer,
+        );
+        "ab".pad_to_buffer_bidi(3, Alignment::Start, ' ', Direction::Auto, &mut buffer);
+        assert_eq!("| שלוםab ", buffer);
+    }
+
+    #[test]
+    fn mutable_rtl_start_keeps_logical_start() {
+        let mut s = String::from("مرحبا");
+        (&mut s).pad_bidi(3, Alignment::Start, '-', Direction::Auto);
+        assert_eq!("مرح", s);
+        (&mut s).pad_bidi(4, Alignment::Start, '-', Direction::Auto);
+        assert_eq!("-مرح", s);
+    }
+}
@@ -0,0 +1,398 @@
+//! The `padder` command line tool, which pads (or truncates) every line read from stdin or
+//! files to a fixed width, using the same semantics as the [`padder`] crate.
+//!
+//! ```text
+//! $ printf 'solaire\nsiegmeyer of catarina\n' | padder --width 12 --align right --fill . --ellipsis …
+//! .....solaire
+//! …of catarina
+//! ```
+//!
+//! The `table` subcommand pretty prints CSV/TSV input as an aligned table, see
+//! [`padder::TableFormat`].
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use padder::{
+    Alignment, ByteWidth, CharWidth, ColumnWidth, MetricSource, TableFormat, WidthMetric,
+};
+
+/// Pads or truncates each line to a fixed width.
+#[derive(Debug, Parser)]
+#[command(
+    name = "padder",
+    version,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The files to read lines from, reads from stdin if none are given.
+    files: Vec<PathBuf>,
+
+    /// The width to pad (or truncate) each line, or each field with `--columns`, to.
+    #[arg(short, long, required = true)]
+    width: Option<usize>,
+
+    /// The alignment of the content, e.g., `left`, `right`, `center`, or `<`, `>`, `^`.
+    #[arg(short, long, default_value = "left")]
+    align: Alignment,
+
+    /// The symbol to pad with.
+    #[arg(short, long, default_value_t = ' ')]
+    fill: char,
+
+    /// The marker which replaces the truncated content, e.g., `…`.
+    #[arg(short, long)]
+    ellipsis: Option<String>,
+
+    /// How the width of the content is measured.
+    #[arg(short = 'm', long, value_enum, default_value_t = WidthMode::Chars)]
+    width_mode: WidthMode,
+
+    /// Splits each line on the delimiter and pads every field instead of the whole line.
+    #[arg(short, long)]
+    columns: bool,
+
+    /// The delimiter which fields are split on (and joined with) in `--columns` mode.
+    #[arg(short, long, default_value = "\t")]
+    delimiter: String,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Pretty prints delimited text (CSV/TSV) as an aligned plain-text table.
+    Table(TableArgs),
+}
+
+#[derive(Debug, Args)]
+struct TableArgs {
+    /// The files to read, each is printed as a table of its own, reads from stdin if none are
+    /// given.
+    files: Vec<PathBuf>,
+
+    /// The delimiter which fields are split on, e.g., `,` or a tab.
+    #[arg(short, long, default_value_t = ',')]
+    delimiter: char,
+
+    /// The separator printed between columns.
+    #[arg(short, long, default_value = "  ")]
+    separator: String,
+
+    /// The maximum width (in terminal columns) of every column, at least 1.
+    #[arg(short, long)]
+    max_width: Option<NonZeroUsize>,
+
+    /// The marker which ends truncated cells.
+    #[arg(long, default_value = "…")]
+    marker: String,
+}
+
+/// How the width of the content is measured.
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum WidthMode {
+    /// The number of utf8 chars.
+    Chars,
+    /// The number of terminal columns, e.g., 2 for wide CJK characters.
+    Columns,
+    /// The number of UTF-8 encoded bytes.
+    Bytes,
+}
+
+impl WidthMode {
+    /// Returns the metric which measures the width of the content.
+    fn metric(self) -> &'static dyn WidthMetric {
+        match self {
+            WidthMode::Chars => &CharWidth,
+            WidthMode::Columns => &ColumnWidth,
+            WidthMode::Bytes => &ByteWidth,
+        }
+    }
+}
+
+/// The options applied to every line (or field).
+#[derive(Debug)]
+struct Fit<'a> {
+    width: usize,
+    mode: Alignment,
+    symbol: char,
+    ellipsis: &'a str,
+    width_mode: WidthMode,
+}
+
+impl Fit<'_> {
+    /// Truncates the `s` into the `buffer`, marking every truncated side with the ellipsis if
+    /// it fits.
+    fn truncate_to_buffer(&self, s: &str, buffer: &mut String) {
+        let metric: &dyn WidthMetric = self.width_mode.metric();
+        let n_sides: usize = match self.mode {
+            Alignment::Left | Alignment::Right | Alignment::Start | Alignment::End => 1,
+            Alignment::Center => 2,
+        };
+        let n_units_ellipsis: usize = metric.measure(self.ellipsis);
+        let ellipsis: &str = if n_sides * n_units_ellipsis <= self.width {
+            self.ellipsis
+        } else {
+            ""
+        };
+
+        let width: usize = self.width - n_sides * metric.measure(ellipsis);
+        let content: &str = s.truncate_to_fit_with_metric(width, self.mode, metric);
+        match self.mode {
+            Alignment::Left | Alignment::Start => {
+                buffer.push_str(content);
+                buffer.push_str(ellipsis);
+            }
+            Alignment::Right | Alignment::End => {
+                buffer.push_str(ellipsis);
+                buffer.push_str(content);
+            }
+            Alignment::Center => {
+                buffer.push_str(ellipsis);
+                buffer.push_str(content);
+                buffer.push_str(ellipsis);
+            }
+        }
+    }
+
+    /// Pads (or truncates) the `s` into the `buffer`, using the `scratch` buffer for truncated
+    /// content.
+    ///
+    /// Units which can not be filled by whole (wide) pad symbols are filled with spaces.
+    fn fit_to_buffer(&self, s: &str, buffer: &mut String, scratch: &mut String) {
+        let metric: &dyn WidthMetric = self.width_mode.metric();
+        let n_bytes_buffer: usize = buffer.len();
+        let content: &str = if metric.measure(s) > self.width {
+            scratch.clear();
+            self.truncate_to_buffer(s, scratch);
+            scratch
+        } else {
+            s
+        };
+        content.pad_to_buffer_with_metric(self.width, self.mode, self.symbol, metric, buffer);
+
+        let n_units_padded: usize = metric.measure(&buffer[n_bytes_buffer..]);
+        let n_spaces: usize = self.width.saturating_sub(n_units_padded);
+        buffer.extend(std::iter::repeat_n(' ', n_spaces));
+    }
+}
+
+/// Pads every line of the `reader` into the `writer`.
+fn run(cli: &Cli, fit: &Fit, reader: impl BufRead, writer: &mut impl Write) -> io::Result<()> {
+    let mut buffer = String::new();
+    let mut scratch = String::new();
+    for line in reader.lines() {
+        let line: String = line?;
+        buffer.clear();
+        if cli.columns {
+            for (idx, field) in line.split(cli.delimiter.as_str()).enumerate() {
+                if idx > 0 {
+                    buffer.push_str(&cli.delimiter);
+                }
+                fit.fit_to_buffer(field, &mut buffer, &mut scratch);
+            }
+        } else {
+            fit.fit_to_buffer(&line, &mut buffer, &mut scratch);
+        }
+        writeln!(writer, "{buffer}")?;
+    }
+    Ok(())
+}
+
+/// Pretty prints every file (or stdin) of the `args` as a table into the `writer`.
+fn run_table(args: &TableArgs, writer: &mut impl Write) -> io::Result<()> {
+    let mut format = TableFormat::new(args.delimiter)
+        .with_separator(args.separator.as_str())
+        .with_marker(args.marker.as_str())
+        .with_metric(ColumnWidth);
+    if let Some(max_width) = args.max_width {
+        format = format.with_max_width(max_width.get());
+    }
+
+    let mut input = String::new();
+    let mut buffer = String::new();
+    if args.files.is_empty() {
+        io::stdin().lock().read_to_string(&mut input)?;
+        format.format_to_buffer(&input, &mut buffer);
+        return writer.write_all(buffer.as_bytes());
+    }
+
+    for path in &args.files {
+        input.clear();
+        buffer.clear();
+        open(path)?.read_to_string(&mut input)?;
+        format.format_to_buffer(&input, &mut buffer);
+        writer.write_all(buffer.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Opens the file at `path`, prefixing any error with the path.
+fn open(path: &PathBuf) -> io::Result<File> {
+    File::open(path).map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut writer = BufWriter::new(io::stdout().lock());
+    let result: io::Result<()> = match (&cli.command, cli.width) {
+        (Some(Command::Table(args)), _) => run_table(args, &mut writer),
+        (None, Some(width)) => {
+            let fit = Fit {
+                width,
+                mode: cli.align,
+                symbol: cli.fill,
+                ellipsis: cli.ellipsis.as_deref().unwrap_or(""),
+                width_mode: cli.width_mode,
+            };
+            if cli.files.is_empty() {
+                run(&cli, &fit, io::stdin().lock(), &mut writer)
+            } else {
+                cli.files
+                    .iter()
+                    .try_for_each(|path| run(&cli, &fit, BufReader::new(open(path)?), &mut writer))
+            }
+        }
+        (None, None) => unreachable!("clap requires the width without a subcommand"),
+    };
+
+    match result.and_then(|_| writer.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("padder: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use padder::Source;
+
+    fn fit(
+        s: &str,
+        width: usize,
+        mode: Alignment,
+        ellipsis: &str,
+        width_mode: WidthMode,
+    ) -> String {
+        let fit = Fit {
+            width,
+            mode,
+            symbol: '*',
+            ellipsis,
+            width_mode,
+        };
+        let mut buffer = String::new();
+        fit.fit_to_buffer(s, &mut buffer, &mut String::new());
+        buffer
+    }
+
+    #[test]
+    fn chars_matches_source() {
+        for mode in [Alignment::Left, Alignment::Right, Alignment::Center] {
+            for width in 0..12 {
+                assert_eq!(
+                    "こんにちは世界".pad(width, mode, '*'),
+                    fit("こんにちは世界", width, mode, "", WidthMode::Chars),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ellipsis() {
+        let s: &str = "siegmeyer of catarina";
+        assert_eq!(
+            "siegmeyer…",
+            fit(s, 10, Alignment::Left, "…", WidthMode::Chars)
+        );
+        assert_eq!(
+            "…catarina",
+            fit(s, 9, Alignment::Right, "…", WidthMode::Chars)
+        );
+        assert_eq!(
+            "…er of …",
+            fit(s, 8, Alignment::Center, "…", WidthMode::Chars)
+        );
+        assert_eq!(" o", fit(s, 2, Alignment::Center, "...", WidthMode::Chars));
+        assert_eq!("", fit(s, 0, Alignment::Left, "…", WidthMode::Chars));
+    }
+
+    #[test]
+    fn columns() {
+        assert_eq!(
+            "**こん**",
+            fit("こん", 8, Alignment::Center, "", WidthMode::Columns)
+        );
+        assert_eq!(
+            "こ*",
+            fit("こんにちは", 3, Alignment::Left, "", WidthMode::Columns)
+        );
+        assert_eq!(
+            "…は",
+            fit("こんにちは", 3, Alignment::Right, "…", WidthMode::Columns)
+        );
+    }
+
+    #[test]
+    fn wide_symbol_leftover_is_spaces() {
+        let fit = Fit {
+            width: 5,
+            mode: Alignment::Left,
+            symbol: 'ツ',
+            ellipsis: "",
+            width_mode: WidthMode::Columns,
+        };
+        let mut buffer = String::new();
+        fit.fit_to_buffer("ab", &mut buffer, &mut String::new());
+        assert_eq!("abツ ", buffer);
+    }
+
+    #[test]
+    fn bytes_never_split_chars() {
+        assert_eq!("ab**", fit("ab", 4, Alignment::Left, "", WidthMode::Bytes));
+        assert_eq!("aé", fit("aéb", 3, Alignment::Left, "", WidthMode::Bytes));
+        assert_eq!("a*", fit("aéb", 2, Alignment::Left, "", WidthMode::Bytes));
+    }
+
+    #[test]
+    fn run_columns() {
+        let cli = Cli::parse_from(["padder", "-w", "4", "-a", ">", "-f", "0", "-c", "-d", ","]);
+        let fit = Fit {
+            width: cli.width.unwrap(),
+            mode: cli.align,
+            symbol: cli.fill,
+            ellipsis: "",
+            width_mode: cli.width_mode,
+        };
+        let mut output: Vec<u8> = Vec::new();
+        run(&cli, &fit, "1,22,333\n4444,55555\n".as_bytes(), &mut output).unwrap();
+        assert_eq!(
+            "0001,0022,0333\n4444,5555\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_table_subcommand() {
+        let cli = Cli::parse_from(["padder", "table", "-d", "\t", "--max-width", "8"]);
+        match cli.command {
+            Some(Command::Table(args)) => {
+                assert_eq!('\t', args.delimiter);
+                assert_eq!(NonZeroUsize::new(8), args.max_width);
+                assert_eq!("…", args.marker);
+            }
+            None => panic!("expected the table subcommand"),
+        }
+        assert!(Cli::try_parse_from(["padder"]).is_err());
+        assert!(Cli::try_parse_from(["padder", "table", "--max-width", "0"]).is_err());
+    }
+}
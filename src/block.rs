@@ -0,0 +1,551 @@
+use std::fmt;
+
+use crate::alignment::Alignment;
+use crate::mutable_source::MutableSource;
+use crate::source::Source;
+
+/// Specifies the scheme used when padding a byte buffer to a multiple of a block size.
+///
+/// - `Zero`: pads with `0x00` bytes, no padding is added if the buffer is already aligned.
+///   Unpadding strips up to `block_size - 1` trailing zeros, so it is ambiguous for data
+///   ending in `0x00`.
+/// - `Pkcs7`: pads with `n` bytes of value `n` ([RFC 5652](https://www.rfc-editor.org/rfc/rfc5652#section-6.3)).
+/// - `AnsiX923`: pads with `n - 1` zero bytes followed by a single byte of value `n`.
+/// - `Iso7816`: pads with a single `0x80` byte followed by `n - 1` zero bytes (ISO/IEC 7816-4).
+///
+/// All schemes except `Zero` always add at least one byte of padding, i.e., a full block of
+/// padding is added if the buffer is already aligned to the block size.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum BlockPadding {
+    #[default]
+    Zero,
+    Pkcs7,
+    AnsiX923,
+    Iso7816,
+}
+
+impl BlockPadding {
+    /// Returns the number of padding bytes that should be appended to a buffer of `len` bytes
+    /// to align it to the `block_size`.
+    ///
+    /// Returns [`BlockPaddingError::InvalidBlockSize`] if the `block_size` can not be
+    /// represented by the padding scheme.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::{BlockPadding, BlockPaddingError};
+    ///
+    /// assert_eq!(Ok(3), BlockPadding::Zero.n_pad_bytes(13, 8));
+    /// assert_eq!(Ok(0), BlockPadding::Zero.n_pad_bytes(16, 8));
+    /// assert_eq!(Ok(8), BlockPadding::Pkcs7.n_pad_bytes(16, 8));
+    /// assert!(matches!(
+    ///     BlockPadding::Pkcs7.n_pad_bytes(16, 0),
+    ///     Err(BlockPaddingError::InvalidBlockSize { .. })
+    /// ));
+    /// ```
+    pub fn n_pad_bytes(&self, len: usize, block_size: usize) -> Result<usize, BlockPaddingError> {
+        self.check_block_size(block_size)?;
+        let n_bytes_rem: usize = len % block_size;
+        Ok(match (self, n_bytes_rem) {
+            (Self::Zero, 0) => 0,
+            _ => block_size - n_bytes_rem,
+        })
+    }
+
+    /// Returns the number of trailing padding bytes in the `data`, validating them according
+    /// to the padding scheme.
+    fn n_padded_bytes(&self, data: &[u8], block_size: usize) -> Result<usize, BlockPaddingError> {
+        self.check_block_size(block_size)?;
+        let n_bytes_rem: usize = data.len() % block_size;
+        if n_bytes_rem != 0 {
+            return Err(BlockPaddingError::Unaligned {
+                len: data.len(),
+                block_size,
+            });
+        }
+
+        match self {
+            // Zero padding never adds a full block, so at most `block_size - 1` bytes are
+            // padding even if the data itself ends with zeros.
+            Self::Zero => {
+                let n: usize = data.len() - data.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+                Ok(n.min(block_size - 1))
+            }
+            Self::Pkcs7 | Self::AnsiX923 => {
+                let n: usize = match data.last() {
+                    Some(&n) => n as usize,
+                    None => return Err(BlockPaddingError::Malformed),
+                };
+                if n == 0 || n > block_size || n > data.len() {
+                    return Err(BlockPaddingError::Malformed);
+                }
+
+                let expected: u8 = match self {
+                    Self::Pkcs7 => n as u8,
+                    _ => 0u8,
+                };
+                let padding: &[u8] = &data[data.len() - n..data.len() - 1];
+                if padding.iter().any(|b| *b != expected) {
+                    return Err(BlockPaddingError::Malformed);
+                }
+                Ok(n)
+            }
+            Self::Iso7816 => {
+                let idx: usize = match data.iter().rposition(|b| *b != 0) {
+                    Some(idx) if data[idx] == 0x80 => idx,
+                    _ => return Err(BlockPaddingError::Malformed),
+                };
+                let n: usize = data.len() - idx;
+                if n > block_size {
+                    return Err(BlockPaddingError::Malformed);
+                }
+                Ok(n)
+            }
+        }
+    }
+
+    /// Writes the padding bytes, assuming `padding` has been zero filled already.
+    fn fill(&self, padding: &mut [u8]) {
+        let n: usize = padding.len();
+        match self {
+            Self::Zero => {}
+            Self::Pkcs7 => padding.fill(n as u8),
+            Self::AnsiX923 => {
+                if let Some(last) = padding.last_mut() {
+                    *last = n as u8;
+                }
+            }
+            Self::Iso7816 => {
+                if let Some(first) = padding.first_mut() {
+                    *first = 0x80;
+                }
+            }
+        }
+    }
+
+    /// Checks that the `block_size` can be represented by the padding scheme.
+    fn check_block_size(&self, block_size: usize) -> Result<(), BlockPaddingError> {
+        let too_large: bool =
+            matches!(self, Self::Pkcs7 | Self::AnsiX923) && block_size > u8::MAX as usize;
+        if block_size == 0 || too_large {
+            return Err(BlockPaddingError::InvalidBlockSize {
+                block_size,
+                scheme: *self,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A trait representing a read-only byte buffer that can be padded to (and unpadded from) a
+/// multiple of a block size, as is common in binary protocols and block ciphers.
+///
+/// All methods return [`BlockPaddingError::InvalidBlockSize`] if `block_size` is `0`, or larger
+/// than `255` for the [`BlockPadding::Pkcs7`] and [`BlockPadding::AnsiX923`] schemes.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let data: &[u8] = b"YELLOW SUBMARINE!";
+/// let padded = data.pad_block(16, BlockPadding::Pkcs7).unwrap();
+/// assert_eq!(32, padded.len());
+/// assert_eq!(&[15u8; 15], &padded[17..]);
+///
+/// assert_eq!(Ok(data), padded.unpad_block(16, BlockPadding::Pkcs7));
+/// assert!(data.pad_block(0, BlockPadding::Pkcs7).is_err());
+/// ```
+pub trait BlockSource {
+    /// Pads the buffer to the next multiple of `block_size` according to the `scheme`.
+    fn pad_block(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<Vec<u8>, BlockPaddingError>;
+
+    /// Pads the buffer to the next multiple of `block_size` according to the `scheme` by
+    /// writing into the provided `buffer`. The `buffer` is left untouched on errors.
+    fn pad_block_to_buffer(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), BlockPaddingError>;
+
+    /// Validates the padding according to the `scheme` and returns the buffer without it.
+    fn unpad_block(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<&[u8], BlockPaddingError>;
+}
+
+/// A trait representing a mutable byte buffer that can be padded to (and unpadded from) a
+/// multiple of a block size in-place.
+///
+/// See [`BlockSource`] for more details.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut v: Vec<u8> = Vec::from(b"ranni");
+/// (&mut v).pad_block(8, BlockPadding::Iso7816).unwrap();
+/// assert_eq!(b"ranni\x80\x00\x00", v.as_slice());
+///
+/// (&mut v).unpad_block(8, BlockPadding::Iso7816).unwrap();
+/// assert_eq!(b"ranni", v.as_slice());
+/// ```
+pub trait MutableBlockSource {
+    /// Pads the buffer in-place to the next multiple of `block_size` according to the `scheme`.
+    /// The buffer is left untouched on errors.
+    fn pad_block(
+        &mut self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<(), BlockPaddingError>;
+
+    /// Validates the padding according to the `scheme` and removes it in-place. The buffer is
+    /// left untouched if the padding is malformed.
+    fn unpad_block(
+        &mut self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<(), BlockPaddingError>;
+}
+
+impl BlockSource for &[u8] {
+    fn pad_block(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<Vec<u8>, BlockPaddingError> {
+        let width: usize = self.len() + scheme.n_pad_bytes(self.len(), block_size)?;
+        let mut output: Vec<u8> = self.pad(width, Alignment::Left, 0u8);
+        scheme.fill(&mut output[self.len()..]);
+        Ok(output)
+    }
+
+    fn pad_block_to_buffer(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), BlockPaddingError> {
+        let width: usize = self.len() + scheme.n_pad_bytes(self.len(), block_size)?;
+        let n_bytes_original: usize = buffer.len();
+        self.pad_to_buffer(width, Alignment::Left, 0u8, buffer);
+        scheme.fill(&mut buffer[n_bytes_original + self.len()..]);
+        Ok(())
+    }
+
+    fn unpad_block(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<&[u8], BlockPaddingError> {
+        let n: usize = scheme.n_padded_bytes(self, block_size)?;
+        Ok(&self[..self.len() - n])
+    }
+}
+
+impl BlockSource for Vec<u8> {
+    fn pad_block(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<Vec<u8>, BlockPaddingError> {
+        self.as_slice().pad_block(block_size, scheme)
+    }
+
+    fn pad_block_to_buffer(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), BlockPaddingError> {
+        self.as_slice()
+            .pad_block_to_buffer(block_size, scheme, buffer)
+    }
+
+    fn unpad_block(
+        &self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<&[u8], BlockPaddingError> {
+        let n: usize = scheme.n_padded_bytes(self, block_size)?;
+        Ok(&self[..self.len() - n])
+    }
+}
+
+impl MutableBlockSource for &mut Vec<u8> {
+    fn pad_block(
+        &mut self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<(), BlockPaddingError> {
+        let n_bytes_original: usize = self.len();
+        let width: usize = n_bytes_original + scheme.n_pad_bytes(n_bytes_original, block_size)?;
+        MutableSource::pad(self, width, Alignment::Left, 0u8);
+        scheme.fill(&mut self[n_bytes_original..]);
+        Ok(())
+    }
+
+    fn unpad_block(
+        &mut self,
+        block_size: usize,
+        scheme: BlockPadding,
+    ) -> Result<(), BlockPaddingError> {
+        let n: usize = scheme.n_padded_bytes(self, block_size)?;
+        self.truncate(self.len() - n);
+        Ok(())
+    }
+}
+
+/// The errors that can occur when adding or removing block padding.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlockPaddingError {
+    /// The length of the buffer was not a multiple of the block size.
+    Unaligned { len: usize, block_size: usize },
+    /// The padding bytes did not match the padding scheme.
+    Malformed,
+    /// The block size was `0`, or too large to be represented by the padding scheme.
+    InvalidBlockSize {
+        block_size: usize,
+        scheme: BlockPadding,
+    },
+}
+
+impl fmt::Display for BlockPaddingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unaligned { len, block_size } => write!(
+                f,
+                "buffer of {len} bytes is not a multiple of the block size {block_size}"
+            ),
+            Self::Malformed => write!(f, "malformed padding"),
+            Self::InvalidBlockSize { block_size, scheme } => {
+                write!(f, "invalid block size {block_size} for {scheme:?} padding")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlockPaddingError {}
+
+#[cfg(test)]
+mod tests_slice {
+    use super::*;
+
+    #[test]
+    fn pad_zero() {
+        let data: &[u8] = &[1, 2, 3];
+        assert_eq!(
+            Ok(Vec::from(&[1u8, 2, 3, 0])),
+            data.pad_block(4, BlockPadding::Zero)
+        );
+        assert_eq!(
+            Ok(Vec::from(&[1u8, 2, 3])),
+            data.pad_block(3, BlockPadding::Zero)
+        );
+    }
+
+    #[test]
+    fn pad_pkcs7() {
+        let data: &[u8] = &[1, 2, 3];
+        assert_eq!(
+            Ok(Vec::from(&[1u8, 2, 3, 5, 5, 5, 5, 5])),
+            data.pad_block(8, BlockPadding::Pkcs7)
+        );
+        assert_eq!(
+            Ok(Vec::from(&[1u8, 2, 3, 3, 3, 3])),
+            data.pad_block(3, BlockPadding::Pkcs7)
+        );
+    }
+
+    #[test]
+    fn pad_ansi_x923() {
+        let data: &[u8] = &[0xde, 0xad];
+        assert_eq!(
+            Ok(Vec::from(&[0xdeu8, 0xad, 0, 0, 0, 4])),
+            data.pad_block(6, BlockPadding::AnsiX923)
+        );
+    }
+
+    #[test]
+    fn pad_iso7816() {
+        let data: &[u8] = &[];
+        assert_eq!(
+            Ok(Vec::from(&[0x80u8, 0, 0, 0])),
+            data.pad_block(4, BlockPadding::Iso7816)
+        );
+    }
+
+    #[test]
+    fn pad_to_buffer() {
+        let data: &[u8] = &[7, 7];
+        let mut buffer: Vec<u8> = Vec::from(&[9u8]);
+        data.pad_block_to_buffer(4, BlockPadding::Pkcs7, &mut buffer)
+            .unwrap();
+        assert_eq!(Vec::from(&[9u8, 7, 7, 2, 2]), buffer);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let data: &[u8] = b"praise the sun";
+        for scheme in [
+            BlockPadding::Zero,
+            BlockPadding::Pkcs7,
+            BlockPadding::AnsiX923,
+            BlockPadding::Iso7816,
+        ] {
+            for block_size in [1, 7, 8, 14, 16, 255] {
+                let padded = data.pad_block(block_size, scheme).unwrap();
+                assert_eq!(0, padded.len() % block_size);
+                assert_eq!(Ok(data), padded.unpad_block(block_size, scheme));
+            }
+        }
+    }
+
+    #[test]
+    fn unpad_unaligned() {
+        let data: Vec<u8> = Vec::from(&[1u8, 2, 3]);
+        assert_eq!(
+            Err(BlockPaddingError::Unaligned {
+                len: 3,
+                block_size: 2
+            }),
+            data.unpad_block(2, BlockPadding::Pkcs7)
+        );
+    }
+
+    #[test]
+    fn unpad_malformed() {
+        let bad_pkcs7: &[u8] = &[1, 2, 3, 3];
+        assert_eq!(
+            Err(BlockPaddingError::Malformed),
+            bad_pkcs7.unpad_block(4, BlockPadding::Pkcs7)
+        );
+
+        let zero_pkcs7: &[u8] = &[1, 2, 3, 0];
+        assert_eq!(
+            Err(BlockPaddingError::Malformed),
+            zero_pkcs7.unpad_block(4, BlockPadding::Pkcs7)
+        );
+
+        let too_long_pkcs7: &[u8] = &[5, 5, 5, 5];
+        assert_eq!(
+            Err(BlockPaddingError::Malformed),
+            too_long_pkcs7.unpad_block(4, BlockPadding::Pkcs7)
+        );
+
+        let bad_x923: &[u8] = &[1, 2, 1, 2];
+        assert_eq!(
+            Err(BlockPaddingError::Malformed),
+            bad_x923.unpad_block(4, BlockPadding::AnsiX923)
+        );
+
+        let bad_iso7816: &[u8] = &[1, 2, 0, 0];
+        assert_eq!(
+            Err(BlockPaddingError::Malformed),
+            bad_iso7816.unpad_block(4, BlockPadding::Iso7816)
+        );
+
+        let empty: &[u8] = &[];
+        assert_eq!(
+            Err(BlockPaddingError::Malformed),
+            empty.unpad_block(4, BlockPadding::Pkcs7)
+        );
+    }
+
+    #[test]
+    fn unpad_zero_bounded() {
+        let data: &[u8] = &[1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(Ok(&data[..5]), data.unpad_block(4, BlockPadding::Zero));
+    }
+
+    #[test]
+    fn unpad_invalid_block_size() {
+        let data: &[u8] = &[1, 2, 3, 1];
+        for scheme in [
+            BlockPadding::Zero,
+            BlockPadding::Pkcs7,
+            BlockPadding::AnsiX923,
+            BlockPadding::Iso7816,
+        ] {
+            assert_eq!(
+                Err(BlockPaddingError::InvalidBlockSize {
+                    block_size: 0,
+                    scheme
+                }),
+                data.unpad_block(0, scheme)
+            );
+        }
+        assert_eq!(
+            Err(BlockPaddingError::InvalidBlockSize {
+                block_size: 256,
+                scheme: BlockPadding::Pkcs7
+            }),
+            data.unpad_block(256, BlockPadding::Pkcs7)
+        );
+    }
+
+    #[test]
+    fn pad_block_size_zero() {
+        let data: &[u8] = &[1];
+        assert_eq!(
+            Err(BlockPaddingError::InvalidBlockSize {
+                block_size: 0,
+                scheme: BlockPadding::Zero
+            }),
+            data.pad_block(0, BlockPadding::Zero)
+        );
+    }
+
+    #[test]
+    fn pkcs7_block_size_too_large() {
+        let data: &[u8] = &[1];
+        let mut buffer: Vec<u8> = Vec::from(&[9u8]);
+        assert!(
+            data.pad_block_to_buffer(256, BlockPadding::Pkcs7, &mut buffer)
+                .is_err()
+        );
+        assert_eq!(Vec::from(&[9u8]), buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests_mut_vec {
+    use super::*;
+
+    #[test]
+    fn pad_pkcs7() {
+        let mut v: Vec<u8> = Vec::from(&[1u8, 2, 3, 4]);
+        (&mut v).pad_block(4, BlockPadding::Pkcs7).unwrap();
+        assert_eq!(Vec::from(&[1u8, 2, 3, 4, 4, 4, 4, 4]), v);
+    }
+
+    #[test]
+    fn pad_invalid_block_size_untouched() {
+        let mut v: Vec<u8> = Vec::from(&[1u8, 2]);
+        assert!((&mut v).pad_block(0, BlockPadding::Iso7816).is_err());
+        assert_eq!(Vec::from(&[1u8, 2]), v);
+    }
+
+    #[test]
+    fn unpad_ansi_x923() {
+        let mut v: Vec<u8> = Vec::from(&[1u8, 0, 0, 3]);
+        (&mut v).unpad_block(4, BlockPadding::AnsiX923).unwrap();
+        assert_eq!(Vec::from(&[1u8]), v);
+    }
+
+    #[test]
+    fn unpad_malformed_untouched() {
+        let mut v: Vec<u8> = Vec::from(&[1u8, 2, 3, 9]);
+        assert_eq!(
+            Err(BlockPaddingError::Malformed),
+            (&mut v).unpad_block(4, BlockPadding::Pkcs7)
+        );
+        assert_eq!(Vec::from(&[1u8, 2, 3, 9]), v);
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::alignment::Alignment;
+use crate::metric::{ByteWidth, pad_metric_in_place, pad_metric_to_sink};
+use crate::mutable_source::MutableSource;
+use crate::source::Source;
+use crate::width::Width;
+
+/// The error returned when the pad symbol is wider than a single unit of the width mode, e.g.,
+/// a multi-byte symbol when padding to a width in bytes. Such symbols could not fill every
+/// width exactly, and are rejected instead.
+///
+/// The `width` of the symbol is given in the units of the width mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymbolWidthError {
+    pub symbol: char,
+    pub width: usize,
+}
+
+impl fmt::Display for SymbolWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pad symbol '{}' is {} units wide, expected a symbol of a single unit",
+            self.symbol, self.width
+        )
+    }
+}
+
+impl std::error::Error for SymbolWidthError {}
+
+/// Returns an error if the `symbol` is encoded in more than a single byte.
+fn check_symbol(symbol: char) -> Result<(), SymbolWidthError> {
+    match symbol.len_utf8() {
+        1 => Ok(()),
+        width => Err(SymbolWidthError { symbol, width }),
+    }
+}
+
+/// A companion trait to [`Source`] for string types which pads (or truncates) to a width in
+/// UTF-8 encoded bytes instead of chars, e.g., for wire formats defining their field widths in
+/// bytes.
+///
+/// This is [`MetricSource`](crate::MetricSource) with the [`ByteWidth`] metric, restricted to
+/// single-byte symbols such that every width can be filled exactly.
+///
+/// Truncation never splits a UTF-8 sequence, instead the bytes which are left over are filled
+/// with the pad `symbol` (according to the alignment `mode`), such that the output is always
+/// exactly `width` bytes long. The pad `symbol` hence has to be a single byte, i.e., ASCII,
+/// otherwise a [`SymbolWidthError`] is returned.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// // 'é' is encoded in 2 bytes.
+/// assert_eq!(Ok(String::from("café ")), "café".pad_bytes(6, Alignment::Left, ' '));
+///
+/// // The 'こ' (3 bytes) does not fit, so the leftover byte is padded.
+/// assert_eq!(Ok(String::from("ab ")), "abこ".pad_bytes(3, Alignment::Left, ' '));
+///
+/// assert!("ab".pad_bytes(3, Alignment::Left, '·').is_err());
+/// ```
+pub trait ByteWidthSource: Source<Symbol = char> {
+    /// Pads (or truncates) the string to the specified `width` (in bytes) using the given
+    /// single-byte `symbol` according to the specified alignment `mode`.
+    fn pad_bytes(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<String, SymbolWidthError>;
+
+    /// Pads (or truncates) the string to the specified `width` (in bytes) using the given
+    /// single-byte `symbol` according to the specified alignment `mode`, appending the result
+    /// to the `buffer`. The `buffer` is left unchanged on errors.
+    fn pad_to_buffer_bytes(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut String,
+    ) -> Result<(), SymbolWidthError>;
+}
+
+impl<S: Source<Symbol = char> + AsRef<str> + ?Sized> ByteWidthSource for S {
+    fn pad_bytes(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<String, SymbolWidthError> {
+        let mut output = String::new();
+        self.pad_to_buffer_bytes(width, mode, symbol, &mut output)?;
+        Ok(output)
+    }
+
+    fn pad_to_buffer_bytes(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut String,
+    ) -> Result<(), SymbolWidthError> {
+        check_symbol(symbol)?;
+        pad_metric_to_sink(self.as_ref(), width, mode, symbol, &ByteWidth, buffer);
+        Ok(())
+    }
+}
+
+/// A companion trait to [`MutableSource`] which pads (or truncates) a string in-place to a width
+/// in UTF-8 encoded bytes, see [`ByteWidthSource`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut s = String::from("ツツツ");
+/// (&mut s).pad_bytes(8, Alignment::Right, '0').unwrap();
+/// assert_eq!("00ツツ", s);
+/// assert_eq!(8, s.len());
+/// ```
+pub trait MutableByteWidthSource: MutableSource<Symbol = char> {
+    /// Pads (or truncates) the string in-place to the specified `width` (in bytes) using the
+    /// given single-byte `symbol` according to the specified alignment `mode`. The string is
+    /// left unchanged on errors.
+    fn pad_bytes(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<(), SymbolWidthError>;
+}
+
+impl MutableByteWidthSource for &mut String {
+    fn pad_bytes(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<(), SymbolWidthError> {
+        check_symbol(symbol)?;
+        pad_metric_in_place(self, width, mode, symbol, &ByteWidth);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pad_bytes_fills_leftover() {
+        let s = String::from("🐉🐉");
+        assert_eq!(
+            Ok(String::from("🐉--")),
+            s.pad_bytes(6, Alignment::Left, '-')
+        );
+        assert_eq!(
+            Ok(String::from("-🐉-")),
+            s.pad_bytes(6, Alignment::Center, '-')
+        );
+        assert_eq!(
+            Ok(String::from("--🐉")),
+            s.pad_bytes(6, Alignment::Right, '-')
+        );
+        assert_eq!(
+            Ok(String::from("🐉🐉")),
+            s.pad_bytes(Width::AtLeast(3), Alignment::Right, '-')
+        );
+        assert_eq!(
+            Ok(String::from("-")),
+            "ツ".pad_bytes(1, Alignment::Center, '-')
+        );
+        assert_eq!(Ok(String::new()), s.pad_bytes(0, Alignment::Right, '-'));
+    }
+
+    #[test]
+    fn pad_to_buffer_bytes_rejects_multibyte_symbol() {
+        let mut buffer = String::from("|");
+        assert_eq!(
+            Err(SymbolWidthError {
+                symbol: 'ツ',
+                width: 3
+            }),
+            "abc".pad_to_buffer_bytes(5, Alignment::Left, 'ツ', &mut buffer)
+        );
+        assert_eq!("|", buffer);
+        "abc"
+            .pad_to_buffer_bytes(5, Alignment::Center, '*', &mut buffer)
+            .unwrap();
+        assert_eq!("|*abc*", buffer);
+    }
+
+    #[test]
+    fn mutable_pad_bytes() {
+        let mut s = String::from("aéiou");
+        (&mut s).pad_bytes(2, Alignment::Left, ' ').unwrap();
+        assert_eq!("a ", s);
+
+        let mut s = String::from("ok");
+        assert!((&mut s).pad_bytes(4, Alignment::Left, 'é').is_err());
+        assert_eq!("ok", s);
+        (&mut s)
+            .pad_bytes(Width::MultipleOf(4), Alignment::Center, '.')
+            .unwrap();
+        assert_eq!(".ok.", s);
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+
+use crate::alignment::Alignment;
+use crate::outcome::PadOutcome;
+use crate::width::Width;
+
+/// The error returned when padding a fixed-capacity buffer would exceed its capacity.
+///
+/// The `capacity` and `required` sizes are given in the units of the buffer's capacity, i.e.,
+/// bytes for strings and items for vectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CapacityError {
+    pub capacity: usize,
+    pub required: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "padded buffer requires a capacity of {} but the capacity is {}",
+            self.required, self.capacity
+        )
+    }
+}
+
+impl std::error::Error for CapacityError {}
+
+/// A trait representing a mutable, fixed-capacity data buffer that can be padded (and
+/// truncated) in-place, e.g., `arrayvec::ArrayString` or `heapless::Vec`.
+///
+/// Contrary to [`MutableSource`](crate::MutableSource) the buffer is never reallocated,
+/// instead a [`CapacityError`] is returned (and the buffer is left unchanged) if the padded
+/// buffer would not fit in its capacity.
+pub trait TryMutableSource {
+    type Symbol;
+
+    /// Pads (or truncates) the buffer in-place to the specified `width` using the given `symbol`
+    /// according to the specified alignment `mode`, failing if the result exceeds the capacity.
+    fn try_pad(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Result<(), CapacityError> {
+        self.try_pad_with_outcome(width, mode, symbol).map(|_| ())
+    }
+
+    /// Pads (or truncates) the buffer in-place like [`TryMutableSource::try_pad`], additionally
+    /// returning a [`PadOutcome`].
+    fn try_pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Result<PadOutcome, CapacityError>;
+}
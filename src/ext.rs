@@ -0,0 +1,67 @@
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+use crate::alignment::{Alignment, Pads};
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+use crate::outcome::PadOutcome;
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+use crate::width::Width;
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "compact_str")]
+mod compact_str;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+
+/// The (possibly truncated) content of a buffer together with the outcome of padding it.
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+pub(crate) struct Layout<'a, S: ?Sized> {
+    pub(crate) content: &'a S,
+    pub(crate) outcome: PadOutcome,
+}
+
+/// Resolves the `width` for the `s` and returns its layout when padded according to the
+/// alignment `mode`, with the byte range of the outcome still expressed in chars.
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+pub(crate) fn str_layout(s: &str, width: impl Into<Width>, mode: Alignment) -> Layout<'_, str> {
+    use crate::metric::{CharWidth, WidthMetric};
+    use crate::source::Source;
+
+    let n_chars: usize = CharWidth.measure(s);
+    let width: usize = width.into().resolve(n_chars);
+    let content: &str = if width < n_chars {
+        s.truncate_to_fit(width, mode)
+    } else {
+        s
+    };
+
+    Layout {
+        content,
+        outcome: PadOutcome::new(n_chars, width, mode),
+    }
+}
+
+/// Resolves the `width` for the `s` and returns its layout when padded according to the
+/// alignment `mode`, with the byte range of the outcome still expressed in items.
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+pub(crate) fn slice_layout<T>(
+    s: &[T],
+    width: impl Into<Width>,
+    mode: Alignment,
+) -> Layout<'_, [T]> {
+    let width: usize = width.into().resolve(s.len());
+    let outcome = PadOutcome::new(s.len(), width, mode);
+    let truncated: Pads = outcome.truncated;
+
+    Layout {
+        content: &s[truncated.left()..s.len() - truncated.right()],
+        outcome,
+    }
+}
+
+/// Returns the number of bytes required to store the `content` padded with `pads` `symbol`s.
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+pub(crate) fn n_bytes_padded(content: &str, pads: Pads, symbol: char) -> usize {
+    content.len() + (pads.left() + pads.right()) * symbol.len_utf8()
+}
@@ -0,0 +1,329 @@
+use ::arrayvec::{ArrayString, ArrayVec};
+
+use crate::alignment::Alignment;
+use crate::capacity::{CapacityError, TryMutableSource};
+use crate::ext::{Layout, n_bytes_padded, slice_layout, str_layout};
+use crate::outcome::{OutcomeSource, PadOutcome};
+use crate::sink::{PadSink, SinkSource};
+use crate::source::Source;
+use crate::utf8::Utf8Buffer;
+use crate::width::Width;
+
+impl<const CAP: usize> Source for ArrayString<CAP> {
+    type Symbol = char;
+    type Buffer = String;
+    type Output = Result<ArrayString<CAP>, CapacityError>;
+    type Slice<'a>
+        = &'a str
+    where
+        Self: 'a;
+
+    /// Truncates the string to match the specified `width` according to the specified
+    /// alignment `mode`, see [`Source::truncate_to_fit`] for `&str`.
+    fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
+        str_layout(self, width, mode).content
+    }
+
+    /// Pads or truncates the string to match the specified `width` (in utf8 chars) according to
+    /// the specified alignment `mode`.
+    ///
+    /// Returns a [`CapacityError`] if the padded string does not fit in `CAP` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use arrayvec::ArrayString;
+    ///
+    /// let s: ArrayString<8> = ArrayString::from("gwyn").unwrap();
+    /// assert_eq!("gwyn----", s.pad(8, Alignment::Left, '-').unwrap().as_str());
+    ///
+    /// let err = s.pad(9, Alignment::Left, '-').unwrap_err();
+    /// assert_eq!(CapacityError { capacity: 8, required: 9 }, err);
+    /// ```
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        self.pad_with_outcome(width, mode, symbol).0
+    }
+
+    /// Pads or truncates the string into the provided (growable) `buffer`.
+    fn pad_to_buffer(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) {
+        self.as_str().pad_to_buffer(width, mode, symbol, buffer);
+    }
+}
+
+impl<const CAP: usize> OutcomeSource for ArrayString<CAP> {
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let width: Width = width.into();
+        let Layout { content, outcome } = str_layout(self, width, mode);
+        let pads = outcome.pads;
+        let n_bytes: usize = n_bytes_padded(content, pads, symbol);
+        let outcome = outcome.with_bytes(symbol.len_utf8(), n_bytes);
+
+        if n_bytes > CAP {
+            let error = CapacityError {
+                capacity: CAP,
+                required: n_bytes,
+            };
+            return (Err(error), outcome);
+        }
+
+        let mut output: ArrayString<CAP> = ArrayString::new();
+        self.as_str().pad_to_sink(width, mode, symbol, &mut output);
+        (Ok(output), outcome)
+    }
+
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        self.as_str()
+            .pad_to_buffer_with_outcome(width, mode, symbol, buffer)
+    }
+}
+
+impl<T, const CAP: usize> Source for ArrayVec<T, CAP>
+where
+    T: Clone + Copy + Sized,
+{
+    type Symbol = T;
+    type Buffer = Vec<T>;
+    type Output = Result<ArrayVec<T, CAP>, CapacityError>;
+    type Slice<'a>
+        = &'a [T]
+    where
+        Self: 'a;
+
+    /// Truncates the vector to match the specified `width` according to the specified
+    /// alignment `mode`, see [`Source::truncate_to_fit`] for `Vec<T>`.
+    fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
+        slice_layout(self, width, mode).content
+    }
+
+    /// Pads or truncates the vector to match the specified `width` according to the specified
+    /// alignment `mode`.
+    ///
+    /// Returns a [`CapacityError`] if `width` exceeds `CAP` items.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use arrayvec::ArrayVec;
+    ///
+    /// let v: ArrayVec<u8, 4> = ArrayVec::from([1, 2, 3, 4]);
+    /// assert_eq!(&[2u8, 3], v.pad(2, Alignment::Center, 0).unwrap().as_slice());
+    /// assert!(v.pad(5, Alignment::Center, 0).is_err());
+    /// ```
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        self.pad_with_outcome(width, mode, symbol).0
+    }
+
+    /// Pads or truncates the vector into the provided (growable) `buffer`.
+    fn pad_to_buffer(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) {
+        self.as_slice().pad_to_buffer(width, mode, symbol, buffer);
+    }
+}
+
+impl<T, const CAP: usize> OutcomeSource for ArrayVec<T, CAP>
+where
+    T: Clone + Copy + Sized,
+{
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let width: Width = width.into();
+        let Layout { content, outcome } = slice_layout(self, width, mode);
+        let pads = outcome.pads;
+        let n_items: usize = pads.left() + content.len() + pads.right();
+        let size: usize = size_of::<T>();
+        let outcome = outcome.with_bytes(size, n_items * size);
+
+        if n_items > CAP {
+            let error = CapacityError {
+                capacity: CAP,
+                required: n_items,
+            };
+            return (Err(error), outcome);
+        }
+
+        let mut output: ArrayVec<T, CAP> = ArrayVec::new();
+        self.as_slice()
+            .pad_to_sink(width, mode, symbol, &mut output);
+        (Ok(output), outcome)
+    }
+
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        self.as_slice()
+            .pad_to_buffer_with_outcome(width, mode, symbol, buffer)
+    }
+}
+
+impl<const CAP: usize> TryMutableSource for &mut ArrayString<CAP> {
+    type Symbol = char;
+
+    /// Pads or truncates the string in-place, the string is left unchanged if the padded
+    /// string does not fit in `CAP` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use arrayvec::ArrayString;
+    ///
+    /// let mut s: ArrayString<6> = ArrayString::from("ornst").unwrap();
+    /// assert!((&mut s).try_pad(7, Alignment::Right, '0').is_err());
+    /// assert_eq!("ornst", s.as_str());
+    ///
+    /// (&mut s).try_pad(6, Alignment::Right, '0').unwrap();
+    /// assert_eq!("0ornst", s.as_str());
+    /// ```
+    fn try_pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Result<PadOutcome, CapacityError> {
+        let (output, outcome) = OutcomeSource::pad_with_outcome(&**self, width, mode, symbol);
+        **self = output?;
+        Ok(outcome)
+    }
+}
+
+impl<T, const CAP: usize> TryMutableSource for &mut ArrayVec<T, CAP>
+where
+    T: Clone + Copy + Sized,
+{
+    type Symbol = T;
+
+    /// Pads or truncates the vector in-place, the vector is left unchanged if `width` exceeds
+    /// `CAP` items.
+    fn try_pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Result<PadOutcome, CapacityError> {
+        let (output, outcome) = OutcomeSource::pad_with_outcome(&**self, width, mode, symbol);
+        **self = output?;
+        Ok(outcome)
+    }
+}
+
+/// Appends to the string like [`ArrayString::push_str`].
+///
+/// # Panics
+/// Panics if the padded content does not fit in `CAP` bytes.
+impl<const CAP: usize> Utf8Buffer for ArrayString<CAP> {
+    fn reserve_bytes(&mut self, _additional: usize) {}
+
+    fn extend_utf8(&mut self, s: &str) {
+        self.push_str(s);
+    }
+
+    fn push_char(&mut self, c: char) {
+        self.push(c);
+    }
+}
+
+/// Appends to the vector like [`ArrayVec::extend`].
+///
+/// # Panics
+/// Panics if the padded content does not fit in `CAP` items.
+impl<T: Clone, const CAP: usize> PadSink<T> for ArrayVec<T, CAP> {
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn push_run(&mut self, symbol: T, n: usize) {
+        self.extend(std::iter::repeat_n(symbol, n));
+    }
+
+    fn push_slice(&mut self, content: &[T]) {
+        self.extend(content.iter().cloned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_string_capacity_multibyte() {
+        let s: ArrayString<8> = ArrayString::from("ab").unwrap();
+        assert_eq!("🦀ab", s.pad(3, Alignment::Right, '🦀').unwrap().as_str());
+        assert_eq!(
+            Err(CapacityError {
+                capacity: 8,
+                required: 10
+            }),
+            s.pad(4, Alignment::Right, '🦀')
+        );
+    }
+
+    #[test]
+    fn array_string_pad_to_buffer() {
+        let s: ArrayString<4> = ArrayString::from("ab").unwrap();
+        let mut buffer = String::new();
+        s.pad_to_buffer(8, Alignment::Center, '.', &mut buffer);
+        assert_eq!("...ab...", buffer);
+    }
+
+    #[test]
+    fn array_vec_try_pad() {
+        let mut v: ArrayVec<u8, 4> = ArrayVec::from_iter([1, 2]);
+        assert_eq!(
+            Err(CapacityError {
+                capacity: 4,
+                required: 8
+            }),
+            (&mut v).try_pad(Width::MultipleOf(8), Alignment::Left, 0)
+        );
+        assert_eq!(&[1u8, 2], v.as_slice());
+
+        let outcome = (&mut v)
+            .try_pad_with_outcome(4, Alignment::Right, 0)
+            .unwrap();
+        assert_eq!(&[0u8, 0, 1, 2], v.as_slice());
+        assert_eq!(2..4, outcome.content);
+    }
+
+    #[test]
+    fn array_vec_truncates_when_full() {
+        let mut v: ArrayVec<char, 3> = ArrayVec::from(['a', 'b', 'c']);
+        (&mut v).try_pad(1, Alignment::Right, ' ').unwrap();
+        assert_eq!(&['c'], v.as_slice());
+    }
+
+    #[test]
+    fn slice_pad_to_array_vec_sink() {
+        let mut buffer: ArrayVec<u8, 4> = ArrayVec::new();
+        [7u8, 7, 7]
+            .as_slice()
+            .pad_to_sink(4, Alignment::Left, 0, &mut buffer);
+        assert_eq!(&[7u8, 7, 7, 0], buffer.as_slice());
+    }
+}
@@ -0,0 +1,170 @@
+use ::compact_str::CompactString;
+
+use crate::alignment::Alignment;
+use crate::metric::{CharWidth, WidthMetric};
+use crate::mutable_source::MutableSource;
+use crate::outcome::{MutableOutcomeSource, OutcomeSource, PadOutcome};
+use crate::sink::SinkSource;
+use crate::source::Source;
+use crate::utf8::Utf8Buffer;
+use crate::width::Width;
+
+impl Source for CompactString {
+    type Symbol = char;
+    type Buffer = CompactString;
+    type Output = CompactString;
+    type Slice<'a>
+        = &'a str
+    where
+        Self: 'a;
+
+    /// Truncates the string to match the specified `width` according to the specified
+    /// alignment `mode`, see [`Source::truncate_to_fit`] for `&str`.
+    fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
+        self.as_str().truncate_to_fit(width, mode)
+    }
+
+    /// Pads or truncates the string to match the specified `width` (in utf8 chars) according to
+    /// the specified alignment `mode`, keeping short results inline.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use compact_str::CompactString;
+    ///
+    /// let s = CompactString::from("melina");
+    /// let o = s.pad(10, Alignment::Center, '✨');
+    /// assert_eq!("✨✨melina✨✨", o);
+    /// assert!(!o.is_heap_allocated());
+    /// ```
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        self.pad_with_outcome(width, mode, symbol).0
+    }
+
+    /// Pads or truncates the string into the provided `buffer`.
+    fn pad_to_buffer(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) {
+        self.pad_to_buffer_with_outcome(width, mode, symbol, buffer);
+    }
+}
+
+impl OutcomeSource for CompactString {
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let mut output = CompactString::default();
+        let outcome = self.pad_to_buffer_with_outcome(width, mode, symbol, &mut output);
+        (output, outcome)
+    }
+
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        let n_chars_original: usize = CharWidth.measure(self);
+        let width: usize = width.into().resolve(n_chars_original);
+        let n_bytes_buffer: usize = buffer.len();
+        self.as_str().pad_to_sink(width, mode, symbol, buffer);
+        PadOutcome::new(n_chars_original, width, mode)
+            .with_bytes(symbol.len_utf8(), buffer.len() - n_bytes_buffer)
+    }
+}
+
+impl MutableSource for &mut CompactString {
+    type Symbol = char;
+    type Buffer = Self;
+
+    /// Pads or truncates the string in-place to match the specified `width` (in utf8 chars)
+    /// according to the specified alignment `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use compact_str::CompactString;
+    ///
+    /// let mut s = CompactString::from("radahn");
+    /// (&mut s).pad(8, Alignment::Right, '*');
+    /// assert_eq!("**radahn", s);
+    /// ```
+    fn pad(&mut self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) {
+        MutableOutcomeSource::pad_with_outcome(self, width, mode, symbol);
+    }
+}
+
+impl MutableOutcomeSource for &mut CompactString {
+    fn pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PadOutcome {
+        let (output, outcome) = OutcomeSource::pad_with_outcome(&**self, width, mode, symbol);
+        **self = output;
+        outcome
+    }
+}
+
+impl Utf8Buffer for CompactString {
+    fn reserve_bytes(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn extend_utf8(&mut self, s: &str) {
+        self.push_str(s);
+    }
+
+    fn push_char(&mut self, c: char) {
+        self.push(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utf8::PadUtf8;
+
+    #[test]
+    fn compact_str_pad_truncates() {
+        let s = CompactString::from("こんにちは");
+        assert_eq!("にちは", s.pad(3, Alignment::Right, ' '));
+        assert_eq!("こんにちは", s.truncate_to_fit(5, Alignment::Left));
+    }
+
+    #[test]
+    fn compact_str_pad_to_buffer() {
+        let s = CompactString::from("ds3");
+        let mut buffer = CompactString::from("|");
+        s.pad_to_buffer(Width::AtLeast(5), Alignment::Left, '.', &mut buffer);
+        assert_eq!("|ds3..", buffer);
+    }
+
+    #[test]
+    fn compact_str_mut_pad_center() {
+        let mut s = CompactString::from("🐉dragon🐉");
+        let outcome = (&mut s).pad_with_outcome(4, Alignment::Center, ' ');
+        assert_eq!("rago", s);
+        assert_eq!(0..4, outcome.bytes);
+
+        (&mut s).pad(7, Alignment::Center, 'ツ');
+        assert_eq!("ツragoツツ", s);
+    }
+
+    #[test]
+    fn str_pad_to_compact_str_sink() {
+        let mut buffer = CompactString::from("[");
+        "elden".pad_to_utf8_buffer(Width::MultipleOf(4), Alignment::End, '_', &mut buffer);
+        assert_eq!("[___elden", buffer);
+        assert!(!buffer.is_heap_allocated());
+    }
+}
@@ -0,0 +1,292 @@
+use ::heapless::{String, Vec};
+
+use crate::alignment::Alignment;
+use crate::capacity::{CapacityError, TryMutableSource};
+use crate::ext::{Layout, n_bytes_padded, slice_layout, str_layout};
+use crate::outcome::{OutcomeSource, PadOutcome};
+use crate::sink::{PadSink, SinkSource};
+use crate::source::Source;
+use crate::utf8::Utf8Buffer;
+use crate::width::Width;
+
+impl<const N: usize> Source for String<N> {
+    type Symbol = char;
+    type Buffer = std::string::String;
+    type Output = Result<String<N>, CapacityError>;
+    type Slice<'a>
+        = &'a str
+    where
+        Self: 'a;
+
+    /// Truncates the string to match the specified `width` according to the specified
+    /// alignment `mode`, see [`Source::truncate_to_fit`] for `&str`.
+    fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
+        str_layout(self, width, mode).content
+    }
+
+    /// Pads or truncates the string to match the specified `width` (in utf8 chars) according to
+    /// the specified alignment `mode`.
+    ///
+    /// Returns a [`CapacityError`] if the padded string does not fit in `N` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let s: heapless::String<8> = heapless::String::try_from("siegward").unwrap();
+    /// assert_eq!("ward", s.pad(4, Alignment::Right, ' ').unwrap());
+    /// assert!(s.pad(9, Alignment::Right, ' ').is_err());
+    /// ```
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        self.pad_with_outcome(width, mode, symbol).0
+    }
+
+    /// Pads or truncates the string into the provided (growable) `buffer`.
+    fn pad_to_buffer(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) {
+        self.as_str().pad_to_buffer(width, mode, symbol, buffer);
+    }
+}
+
+impl<const N: usize> OutcomeSource for String<N> {
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let width: Width = width.into();
+        let Layout { content, outcome } = str_layout(self, width, mode);
+        let n_bytes: usize = n_bytes_padded(content, outcome.pads, symbol);
+        let outcome = outcome.with_bytes(symbol.len_utf8(), n_bytes);
+
+        if n_bytes > N {
+            let error = CapacityError {
+                capacity: N,
+                required: n_bytes,
+            };
+            return (Err(error), outcome);
+        }
+
+        let mut output: String<N> = String::new();
+        self.as_str().pad_to_sink(width, mode, symbol, &mut output);
+        (Ok(output), outcome)
+    }
+
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        self.as_str()
+            .pad_to_buffer_with_outcome(width, mode, symbol, buffer)
+    }
+}
+
+impl<T, const N: usize> Source for Vec<T, N>
+where
+    T: Clone + Copy + Sized,
+{
+    type Symbol = T;
+    type Buffer = std::vec::Vec<T>;
+    type Output = Result<Vec<T, N>, CapacityError>;
+    type Slice<'a>
+        = &'a [T]
+    where
+        Self: 'a;
+
+    /// Truncates the vector to match the specified `width` according to the specified
+    /// alignment `mode`, see [`Source::truncate_to_fit`] for `Vec<T>`.
+    fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
+        slice_layout(self, width, mode).content
+    }
+
+    /// Pads or truncates the vector to match the specified `width` according to the specified
+    /// alignment `mode`.
+    ///
+    /// Returns a [`CapacityError`] if `width` exceeds `N` items.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let v: heapless::Vec<i8, 4> = heapless::Vec::from_slice(&[-1, 1]).unwrap();
+    /// assert_eq!(&[-1i8, 1, 0, 0], v.pad(4, Alignment::Left, 0).unwrap().as_slice());
+    /// assert!(v.pad(Width::AtLeast(5), Alignment::Left, 0).is_err());
+    /// ```
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        self.pad_with_outcome(width, mode, symbol).0
+    }
+
+    /// Pads or truncates the vector into the provided (growable) `buffer`.
+    fn pad_to_buffer(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) {
+        self.as_slice().pad_to_buffer(width, mode, symbol, buffer);
+    }
+}
+
+impl<T, const N: usize> OutcomeSource for Vec<T, N>
+where
+    T: Clone + Copy + Sized,
+{
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let width: Width = width.into();
+        let Layout { content, outcome } = slice_layout(self, width, mode);
+        let pads = outcome.pads;
+        let n_items: usize = pads.left() + content.len() + pads.right();
+        let size: usize = size_of::<T>();
+        let outcome = outcome.with_bytes(size, n_items * size);
+
+        if n_items > N {
+            let error = CapacityError {
+                capacity: N,
+                required: n_items,
+            };
+            return (Err(error), outcome);
+        }
+
+        let mut output: Vec<T, N> = Vec::new();
+        self.as_slice()
+            .pad_to_sink(width, mode, symbol, &mut output);
+        (Ok(output), outcome)
+    }
+
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        self.as_slice()
+            .pad_to_buffer_with_outcome(width, mode, symbol, buffer)
+    }
+}
+
+impl<const N: usize> TryMutableSource for &mut String<N> {
+    type Symbol = char;
+
+    /// Pads or truncates the string in-place, the string is left unchanged if the padded
+    /// string does not fit in `N` bytes.
+    fn try_pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Result<PadOutcome, CapacityError> {
+        let (output, outcome) = OutcomeSource::pad_with_outcome(&**self, width, mode, symbol);
+        **self = output?;
+        Ok(outcome)
+    }
+}
+
+impl<T, const N: usize> TryMutableSource for &mut Vec<T, N>
+where
+    T: Clone + Copy + Sized,
+{
+    type Symbol = T;
+
+    /// Pads or truncates the vector in-place, the vector is left unchanged if `width` exceeds
+    /// `N` items.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let mut v: heapless::Vec<u8, 8> = heapless::Vec::from_slice(&[0xde, 0xad]).unwrap();
+    /// (&mut v).try_pad(Width::MultipleOf(8), Alignment::Left, 0).unwrap();
+    /// assert_eq!(&[0xde, 0xad, 0, 0, 0, 0, 0, 0], v.as_slice());
+    /// ```
+    fn try_pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Result<PadOutcome, CapacityError> {
+        let (output, outcome) = OutcomeSource::pad_with_outcome(&**self, width, mode, symbol);
+        **self = output?;
+        Ok(outcome)
+    }
+}
+
+/// Appends to the string like [`String::push_str`].
+///
+/// # Panics
+/// Panics if the padded content does not fit in `N` bytes.
+impl<const N: usize> Utf8Buffer for String<N> {
+    fn reserve_bytes(&mut self, _additional: usize) {}
+
+    fn extend_utf8(&mut self, s: &str) {
+        self.push_str(s)
+            .expect("the padded content should fit in the heapless::String");
+    }
+}
+
+/// Appends to the vector like [`Vec::extend`].
+///
+/// # Panics
+/// Panics if the padded content does not fit in `N` items.
+impl<T: Clone, const N: usize> PadSink<T> for Vec<T, N> {
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn push_run(&mut self, symbol: T, n: usize) {
+        self.extend(std::iter::repeat_n(symbol, n));
+    }
+
+    fn push_slice(&mut self, content: &[T]) {
+        self.extend(content.iter().cloned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heapless_string_try_pad() {
+        let mut s: String<6> = String::try_from("ツツ").unwrap();
+        assert_eq!(
+            Err(CapacityError {
+                capacity: 6,
+                required: 7
+            }),
+            (&mut s).try_pad(3, Alignment::Left, '!')
+        );
+        assert_eq!("ツツ", s);
+
+        (&mut s).try_pad(1, Alignment::Left, '!').unwrap();
+        assert_eq!("ツ", s);
+    }
+
+    #[test]
+    fn heapless_vec_pad_to_buffer() {
+        let v: Vec<u8, 2> = Vec::from_slice(&[1, 2]).unwrap();
+        let mut buffer: std::vec::Vec<u8> = std::vec::Vec::new();
+        v.pad_to_buffer(6, Alignment::Center, 0, &mut buffer);
+        assert_eq!(std::vec::Vec::from(&[0u8, 0, 1, 2, 0, 0]), buffer);
+    }
+
+    #[test]
+    fn str_pad_to_heapless_string_sink() {
+        let mut buffer: String<8> = String::new();
+        "solaire".pad_to_sink(8, Alignment::Center, '\\', &mut buffer);
+        assert_eq!("solaire\\", buffer);
+    }
+}
@@ -0,0 +1,188 @@
+use ::smallvec::{Array, SmallVec};
+
+use crate::alignment::Alignment;
+use crate::mutable_source::MutableSource;
+use crate::outcome::{MutableOutcomeSource, OutcomeSource, PadOutcome};
+use crate::sink::{PadSink, SinkSource};
+use crate::source::Source;
+use crate::width::Width;
+
+impl<A: Array> Source for SmallVec<A>
+where
+    A::Item: Clone + Copy + Sized,
+{
+    type Symbol = A::Item;
+    type Buffer = SmallVec<A>;
+    type Output = SmallVec<A>;
+    type Slice<'a>
+        = &'a [A::Item]
+    where
+        Self: 'a;
+
+    /// Truncates the smallvec to match the specified `width` according to the specified
+    /// alignment `mode`, see [`Source::truncate_to_fit`] for `Vec<T>`.
+    fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
+        let truncated = PadOutcome::new(self.len(), width, mode).truncated;
+        &self[truncated.left()..self.len() - truncated.right()]
+    }
+
+    /// Pads or truncates the smallvec to match the specified `width` according to the specified
+    /// alignment `mode`, only spilling to the heap if `width` exceeds the inline capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use smallvec::SmallVec;
+    ///
+    /// let v: SmallVec<[u8; 8]> = SmallVec::from_slice(&[4, 5, 1]);
+    /// let o = v.pad(6, Alignment::Center, 0u8);
+    /// assert_eq!(&[0u8, 4, 5, 1, 0, 0], o.as_slice());
+    /// assert!(!o.spilled());
+    /// ```
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        self.pad_with_outcome(width, mode, symbol).0
+    }
+
+    /// Pads or truncates the smallvec into the provided `buffer`.
+    fn pad_to_buffer(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) {
+        self.pad_to_buffer_with_outcome(width, mode, symbol, buffer);
+    }
+}
+
+impl<A: Array> OutcomeSource for SmallVec<A>
+where
+    A::Item: Clone + Copy + Sized,
+{
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let mut output: SmallVec<A> = SmallVec::new();
+        let outcome = self.pad_to_buffer_with_outcome(width, mode, symbol, &mut output);
+        (output, outcome)
+    }
+
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        let width: usize = width.into().resolve(self.len());
+        let n_items_buffer: usize = buffer.len();
+        self.as_slice().pad_to_sink(width, mode, symbol, buffer);
+
+        let size: usize = size_of::<A::Item>();
+        PadOutcome::new(self.len(), width, mode)
+            .with_bytes(size, (buffer.len() - n_items_buffer) * size)
+    }
+}
+
+impl<A: Array> MutableSource for &mut SmallVec<A>
+where
+    A::Item: Copy + Sized,
+{
+    type Symbol = A::Item;
+    type Buffer = Self;
+
+    /// Pads or truncates the smallvec in-place to match the specified `width` according to the
+    /// specified alignment `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use smallvec::SmallVec;
+    ///
+    /// let mut v: SmallVec<[char; 4]> = SmallVec::from_slice(&['a', 'b', 'c']);
+    /// (&mut v).pad(2, Alignment::Right, ' ');
+    /// assert_eq!(&['b', 'c'], v.as_slice());
+    /// ```
+    fn pad(&mut self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) {
+        MutableOutcomeSource::pad_with_outcome(self, width, mode, symbol);
+    }
+}
+
+impl<A: Array> MutableOutcomeSource for &mut SmallVec<A>
+where
+    A::Item: Copy + Sized,
+{
+    fn pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PadOutcome {
+        let (output, outcome) = OutcomeSource::pad_with_outcome(&**self, width, mode, symbol);
+        **self = output;
+        outcome
+    }
+}
+
+impl<A: Array> PadSink<A::Item> for SmallVec<A>
+where
+    A::Item: Clone,
+{
+    fn reserve(&mut self, additional: usize) {
+        SmallVec::reserve(self, additional);
+    }
+
+    fn push_run(&mut self, symbol: A::Item, n: usize) {
+        self.extend(std::iter::repeat_n(symbol, n));
+    }
+
+    fn push_slice(&mut self, content: &[A::Item]) {
+        self.extend(content.iter().cloned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smallvec_pad_to_buffer() {
+        let v: SmallVec<[u16; 4]> = SmallVec::from_slice(&[7, 7]);
+        let mut buffer: SmallVec<[u16; 4]> = SmallVec::from_slice(&[1]);
+        v.pad_to_buffer(3, Alignment::Left, 0, &mut buffer);
+        assert_eq!(&[1u16, 7, 7, 0], buffer.as_slice());
+        assert!(!buffer.spilled());
+    }
+
+    #[test]
+    fn slice_pad_to_smallvec_sink() {
+        let mut buffer: SmallVec<[u8; 8]> = SmallVec::new();
+        [3u8, 1]
+            .as_slice()
+            .pad_to_sink(5, Alignment::Right, 0, &mut buffer);
+        assert_eq!(&[0u8, 0, 0, 3, 1], buffer.as_slice());
+        assert!(!buffer.spilled());
+    }
+
+    #[test]
+    fn smallvec_pad_spills() {
+        let v: SmallVec<[u8; 2]> = SmallVec::from_slice(&[1, 2]);
+        let o = v.pad(Width::MultipleOf(8), Alignment::Right, 0);
+        assert_eq!(&[0u8, 0, 0, 0, 0, 0, 1, 2], o.as_slice());
+        assert!(o.spilled());
+    }
+
+    #[test]
+    fn smallvec_mut_pad() {
+        let mut v: SmallVec<[char; 8]> = SmallVec::from_slice(&['y', 'o']);
+        let outcome = (&mut v).pad_with_outcome(5, Alignment::Center, '-');
+        assert_eq!(&['-', 'y', 'o', '-', '-'], v.as_slice());
+        assert_eq!(1..3, outcome.content);
+
+        (&mut v).pad(3, Alignment::Center, '-');
+        assert_eq!(&['y', 'o', '-'], v.as_slice());
+    }
+}
@@ -0,0 +1,257 @@
+use std::iter::FusedIterator;
+use std::str::Chars;
+
+use crate::alignment::Alignment;
+use crate::width::Width;
+
+/// An iterator adapter which lazily yields the left padding symbols, then the (possibly
+/// truncated) items of the inner iterator, then the right padding symbols.
+///
+/// This struct is created by [`PadIterator::pad_iter`] or [`PadChars::pad_chars`].
+#[derive(Debug, Clone)]
+pub struct Padded<I: Iterator> {
+    iter: I,
+    symbol: I::Item,
+    n_skip: usize,
+    n_left: usize,
+    n_items: usize,
+    n_right: usize,
+}
+
+impl<I: Iterator> Padded<I> {
+    /// Creates a new adapter padding (or truncating) the `n_items` long `iter` to the `width`.
+    fn new(
+        iter: I,
+        n_items: usize,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: I::Item,
+    ) -> Self {
+        let width: usize = width.into().resolve(n_items);
+        if width < n_items {
+            let n_skip: usize = match mode {
+                Alignment::Left | Alignment::Start => 0,
+                Alignment::Right | Alignment::End => n_items - width,
+                Alignment::Center => (n_items - width) / 2,
+            };
+            return Self {
+                iter,
+                symbol,
+                n_skip,
+                n_left: 0,
+                n_items: width,
+                n_right: 0,
+            };
+        }
+
+        let pads = mode.pads(width - n_items);
+        Self {
+            iter,
+            symbol,
+            n_skip: 0,
+            n_left: pads.left(),
+            n_items,
+            n_right: pads.right(),
+        }
+    }
+}
+
+impl<I> Iterator for Padded<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n_left > 0 {
+            self.n_left -= 1;
+            return Some(self.symbol.clone());
+        }
+
+        if self.n_items > 0 {
+            let item = if self.n_skip > 0 {
+                let n_skip: usize = std::mem::take(&mut self.n_skip);
+                self.iter.nth(n_skip)
+            } else {
+                self.iter.next()
+            };
+            if item.is_none() {
+                // The inner iterator reported a wrong `len()`, so the right padding is dropped
+                // as well to stay fused.
+                self.n_items = 0;
+                self.n_right = 0;
+                return None;
+            }
+            self.n_items -= 1;
+            return item;
+        }
+
+        if self.n_right > 0 {
+            self.n_right -= 1;
+            return Some(self.symbol.clone());
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n: usize = self.n_left + self.n_items + self.n_right;
+        (n, Some(n))
+    }
+}
+
+impl<I> ExactSizeIterator for Padded<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+/// The adapter is fused even if the inner iterator is not: if the inner iterator ends before
+/// yielding as many items as its [`ExactSizeIterator::len`] promised, the right padding is
+/// dropped and `None` is returned from then on.
+impl<I> FusedIterator for Padded<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+/// An extension trait for [`ExactSizeIterator`]s which pads (or truncates) the items lazily,
+/// with the same semantics as [`crate::Source`] for `Vec<T>` and `&[T]`, but without
+/// collecting them.
+///
+/// The method is named `pad_iter` rather than `pad` such that it does not clash with
+/// [`crate::Source::pad`] on the many types which are both sources and iterators.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let souls = [1000u32, 2000, 3000].into_iter().map(|s| s / 1000);
+/// let padded: Vec<u32> = souls.pad_iter(6, Alignment::Center, 0).collect();
+/// assert_eq!(vec![0, 1, 2, 3, 0, 0], padded);
+///
+/// let truncated: Vec<u8> = (0u8..10).pad_iter(Width::AtMost(3), Alignment::Right, 0).collect();
+/// assert_eq!(vec![7, 8, 9], truncated);
+/// ```
+pub trait PadIterator: ExactSizeIterator + Sized {
+    /// Pads (or truncates) the iterator to the specified `width` using the given `symbol`
+    /// according to the specified alignment `mode`.
+    fn pad_iter(
+        self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Item,
+    ) -> Padded<Self> {
+        let n_items: usize = self.len();
+        Padded::new(self, n_items, width, mode, symbol)
+    }
+}
+
+impl<I: ExactSizeIterator> PadIterator for I {}
+
+/// A trait for lazily padding (or truncating) the chars of a string, see [`PadIterator`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let padded: String = "kiln".pad_chars(8, Alignment::Left, '🔥').collect();
+/// assert_eq!("kiln🔥🔥🔥🔥", padded);
+///
+/// let shouted: String = "kiln".pad_chars(6, Alignment::Center, '!').flat_map(char::to_uppercase).collect();
+/// assert_eq!("!KILN!", shouted);
+/// ```
+pub trait PadChars {
+    /// Pads (or truncates) the chars to the specified `width` (in utf8 chars) using the given
+    /// `symbol` according to the specified alignment `mode`.
+    fn pad_chars(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Padded<Chars<'_>>;
+}
+
+impl PadChars for str {
+    fn pad_chars(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Padded<Chars<'_>> {
+        Padded::new(self.chars(), self.chars().count(), width, mode, symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::Source;
+
+    #[test]
+    fn iter_pad_matches_vec() {
+        let v: Vec<u8> = vec![1, 2, 3, 4, 5];
+        for mode in [Alignment::Left, Alignment::Right, Alignment::Center] {
+            for width in 0..9 {
+                let padded: Vec<u8> = v.iter().copied().pad_iter(width, mode, 0).collect();
+                assert_eq!(v.pad(width, mode, 0), padded);
+            }
+        }
+    }
+
+    #[test]
+    fn chars_pad_matches_str() {
+        let s: &str = "こんにちは";
+        for mode in [Alignment::Left, Alignment::Right, Alignment::Center] {
+            for width in 0..9 {
+                let padded: String = s.pad_chars(width, mode, '-').collect();
+                assert_eq!(s.pad(width, mode, '-'), padded);
+            }
+        }
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut padded = (0..3).pad_iter(5, Alignment::Right, -1);
+        assert_eq!(5, padded.len());
+        padded.next();
+        assert_eq!(4, padded.len());
+        assert_eq!(vec![-1, 0, 1, 2], padded.collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn truncate_center_skips_lazily() {
+        let mut padded = (0..7).pad_iter(3, Alignment::Center, 0);
+        assert_eq!(3, padded.len());
+        assert_eq!(vec![2, 3, 4], padded.by_ref().collect::<Vec<i32>>());
+        assert_eq!(None, padded.next());
+    }
+
+    /// An iterator which claims to be longer than it is.
+    struct Liar(std::ops::Range<u8>);
+
+    impl Iterator for Liar {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len() + 2, Some(self.0.len() + 2))
+        }
+    }
+
+    impl ExactSizeIterator for Liar {}
+
+    #[test]
+    fn fused_on_short_inner() {
+        let mut padded = Liar(0..2).pad_iter(6, Alignment::Left, 9);
+        assert_eq!(vec![0, 1], padded.by_ref().collect::<Vec<u8>>());
+        assert_eq!(None, padded.next());
+        assert_eq!(0, padded.len());
+    }
+}
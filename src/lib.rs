@@ -13,9 +13,53 @@
 //!
 //! # Features
 //! - Pad strings, slices, and vectors with custom alignment and width.
+//! - Align right-to-left text (Arabic, Hebrew) by its logical start or end through
+//!   [`Alignment::Start`], [`Alignment::End`], and [`BidiSource`].
+//! - Normalize strings (NFC/NFKC) before measuring their width, so padded widths are stable
+//!   regardless of the composition form, with the `unicode-normalization` feature.
+//! - Pad strings to widths in UTF-8 encoded bytes, without ever splitting a UTF-8 sequence,
+//!   through [`ByteWidthSource`].
+//! - Pad strings and `u16` buffers to widths in UTF-16 code units, e.g., for JavaScript, Java or
+//!   Windows APIs, without ever splitting a surrogate pair, through [`Utf16WidthSource`] and
+//!   [`Utf16Source`].
+//! - Plug custom width calculations (e.g., font-based widths) into padding and truncation through
+//!   [`WidthMetric`] and [`MetricSource`], with built-in metrics for chars, bytes, UTF-16 code
+//!   units, grapheme clusters (`unicode-segmentation` feature), and display columns
+//!   (`unicode-width` feature).
+//! - Pad to exact widths, minimum/maximum widths, or multiples of a width through [`Width`].
+//! - Pad [`std::ffi::OsStr`]s, [`std::ffi::OsString`]s, and [`std::path::Path`]s losslessly, with
+//!   path-aware truncation that elides middle components (`/home/u/…/src/main.rs`).
+//! - Truncate path-like strings by eliding middle components through [`Truncation`] and the
+//!   [`TruncatingSource`] and [`MutableTruncatingSource`] traits.
 //! - Zero-cost abstractions via the `Source` and `MutableSource` traits.
 //! - Pad directly into buffers for fine-grained heap allocation control.
+//! - Pad into any buffer (`VecDeque`, `Cursor`, custom arenas) implementing [`PadSink`] through
+//!   [`SinkSource`].
+//! - Lazily pad any `ExactSizeIterator` (or the chars of a string) through [`PadIterator`] and
+//!   [`PadChars`].
+//! - Pad strings directly into UTF-8 byte buffers (`Vec<u8>`, or `bytes::BytesMut` with the
+//!   `bytes` feature) through [`PadUtf8`].
+//! - Inspect where the content ended up after padding through [`PadOutcome`] ([`OutcomeSource`]).
+//! - Strip the padding again through the `Unpad` and `MutableUnpad` traits.
+//! - Pad byte buffers to block sizes (PKCS#7, ANSI X9.23, ISO/IEC 7816-4, zeros) through the
+//!   [`BlockSource`] and [`MutableBlockSource`] traits.
+//! - Pad ragged 2D data (`Vec<Vec<T>>`) into rectangular shapes, optionally as a flat buffer
+//!   with a padding mask, through the [`MatrixSource`] trait.
 //! - Highly extensible to custom types through the provided traits.
+//! - Write and read fixed-width records (flat files) through a [`RecordSchema`].
+//! - Derive [`PadRecord`] for custom structs with the `derive` feature.
+//! - Compile-time validated `pad!` macro with the `macros` feature.
+//! - Parse [`std::fmt`]-style specs like `*^20` at runtime through [`FormatSpec`].
+//! - Pad `SmallVec`s and `CompactString`s, and fixed-capacity `ArrayString`s, `ArrayVec`s,
+//!   and `heapless` strings/vectors (returning a [`CapacityError`] instead of reallocating)
+//!   with the `smallvec`, `compact_str`, `arrayvec`, and `heapless` features.
+//! - Write padded content straight to `AsyncWrite` sinks, and pad outgoing frames with a
+//!   `PadEncoder` codec, with the `tokio` and `futures-io` features.
+//! - Pretty print CSV/TSV input as an aligned plain-text table through [`TableFormat`].
+//! - Pad (or truncate) lines and delimited fields from the shell with the `padder` binary and
+//!   the `cli` feature.
+//! - (De)serialize alignments, specs, and record layouts with the `serde` feature.
+//! - Serialize structs directly into fixed-width lines (and back) with the `serde` feature.
 //!
 //! # Usage
 //! ```
@@ -36,13 +80,114 @@
 //! ```
 //!
 
+// Allows the procedural macros to refer to `::padder` from within this crate.
+#[cfg(any(feature = "derive", feature = "macros"))]
+extern crate self as padder;
+
 mod alignment;
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+mod async_io;
+mod bidi;
+mod block;
+mod byte_width;
+mod capacity;
+#[cfg(any(
+    feature = "arrayvec",
+    feature = "compact_str",
+    feature = "heapless",
+    feature = "smallvec"
+))]
+mod ext;
+mod iter;
+mod matrix;
+mod metric;
 mod mutable_source;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+mod os_str;
+mod outcome;
+mod path;
+mod record;
+mod sink;
 mod source;
+mod spec;
+mod table;
+mod truncation;
+mod unpad;
+mod utf16;
+mod utf8;
+mod width;
 
-pub use alignment::{Alignment, Pads};
+pub use alignment::{Alignment, Pads, ParseAlignmentError};
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+pub use async_io::AsyncPadSource;
+#[cfg(feature = "tokio")]
+pub use async_io::PadEncoder;
+pub use bidi::{BidiSource, Direction, MutableBidiSource};
+pub use block::{BlockPadding, BlockPaddingError, BlockSource, MutableBlockSource};
+pub use byte_width::{ByteWidthSource, MutableByteWidthSource, SymbolWidthError};
+pub use capacity::{CapacityError, TryMutableSource};
+pub use iter::{PadChars, PadIterator, Padded};
+pub use matrix::{MatrixSource, PaddedMatrix, Shape};
+#[cfg(feature = "unicode-width")]
+pub use metric::ColumnWidth;
+#[cfg(feature = "unicode-segmentation")]
+pub use metric::GraphemeWidth;
+pub use metric::{
+    ByteWidth, CharWidth, MetricSource, MutableMetricSource, Utf16Width, WidthMetric,
+};
 pub use mutable_source::MutableSource;
+#[cfg(feature = "unicode-normalization")]
+pub use normalize::{MutableNormalizedSource, Normalization, NormalizedSource};
+pub use outcome::{MutableOutcomeSource, OutcomeSource, PadOutcome};
+pub use record::{
+    Overflow, PadRecord, RecordError, RecordField, RecordReader, RecordSchema, RecordWriter,
+};
+pub use sink::{PadSink, SinkSource};
 pub use source::Source;
+pub use spec::{FormatSpec, ParseSpecError};
+pub use table::TableFormat;
+pub use truncation::{MutableTruncatingSource, TruncatingSource, Truncation};
+pub use unpad::{MutableUnpad, Unpad};
+pub use utf8::{PadUtf8, Utf8Buffer};
+pub use utf16::{MutableUtf16WidthSource, Utf16Source, Utf16WidthSource};
+pub use width::Width;
+
+#[cfg(feature = "derive")]
+pub use padder_macros::PadRecord;
+
+/// Pads each argument according to its placeholder in the format string and concatenates the
+/// results into a single preallocated `String`.
+///
+/// This is the padder counterpart to the builtin `format!` macro. Every placeholder has to be
+/// written as `{:[fill]align width}` where `align` is one of `<` (left), `^` (center), or `>`
+/// (right), and the `fill` symbol defaults to `' '`. Contrary to `format!`, arguments that are
+/// wider than their placeholder are truncated, see [`Source::pad`].
+///
+/// The format string is validated at compile time, and the output is built with
+/// [`Source::pad_to_buffer`] into a `String` which is allocated exactly once. Each argument has
+/// to implement `AsRef<str>`.
+///
+/// Literal braces are escaped as `{{` and `}}`.
+///
+/// Requires the `macros` feature.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "macros")]
+/// # {
+/// use padder::*;
+///
+/// let name = String::from("siegmeyer");
+/// let line = pad!("[{:<10}|{:*^7}|{:0>4}]", name, "onion", "42");
+/// assert_eq!("[siegmeyer |*onion*|0042]", line);
+///
+/// let truncated = pad!("{:>3}", "catarina");
+/// assert_eq!("ina", truncated);
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+pub use padder_macros::pad;
 
 /// Pads the given source buffer to the specified `width` using the provided `symbol` and alignment `mode`.
 ///
@@ -65,7 +210,12 @@ pub use source::Source;
 /// let padded = pad(vec, 6, Alignment::Center, 0usize);
 /// assert_eq!(Vec::from(&[0usize, 200, 10, 23, 0, 0]), padded);
 /// ```
-pub fn pad<S: Source>(source: S, width: usize, mode: Alignment, symbol: S::Symbol) -> S::Output {
+pub fn pad<S: Source>(
+    source: S,
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: S::Symbol,
+) -> S::Output {
     source.pad(width, mode, symbol)
 }
 
@@ -86,7 +236,12 @@ pub fn pad<S: Source>(source: S, width: usize, mode: Alignment, symbol: S::Symbo
 /// pad_mut(&mut string, 14, Alignment::Center, '🌑');
 /// assert_eq!("🌑🌑dark souls🌑🌑", string);
 /// ```
-pub fn pad_mut<S: MutableSource>(mut source: S, width: usize, mode: Alignment, symbol: S::Symbol) {
+pub fn pad_mut<S: MutableSource>(
+    mut source: S,
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: S::Symbol,
+) {
     source.pad(width, mode, symbol);
 }
 
@@ -121,7 +276,7 @@ pub fn pad_mut<S: MutableSource>(mut source: S, width: usize, mode: Alignment, s
 /// ```
 pub fn pad_to_buffer<S: Source>(
     source: S,
-    width: usize,
+    width: impl Into<Width>,
     mode: Alignment,
     symbol: S::Symbol,
     buffer: &mut S::Buffer,
@@ -129,6 +284,46 @@ pub fn pad_to_buffer<S: Source>(
     source.pad_to_buffer(width, mode, symbol, buffer);
 }
 
+/// Trims the padding `symbol`s from the given source buffer according to the alignment `mode`
+/// that was used when padding it.
+///
+/// This is a convenience wrapper around the [`Unpad::unpad`] method. It does not allocate and
+/// instead returns a borrowed view into the source buffer.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let s = String::from("__this is a string__");
+/// assert_eq!("this is a string", unpad(&s, Alignment::Center, '_'));
+///
+/// let vec: Vec<usize> = Vec::from(&[0usize, 200, 10, 23, 0, 0]);
+/// assert_eq!(&[0usize, 200, 10, 23], unpad(&vec, Alignment::Left, 0usize));
+/// ```
+pub fn unpad<'a, S: Unpad>(source: &'a S, mode: Alignment, symbol: S::Symbol) -> S::Slice<'a>
+where
+    S::Slice<'a>: Sized,
+{
+    source.unpad(mode, symbol)
+}
+
+/// Trims the padding `symbol`s from the given mutable source buffer in-place according to the
+/// alignment `mode` that was used when padding it.
+///
+/// This is a convenience wrapper around the [`MutableUnpad::unpad`] method.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut string = String::from("elden ring💍💍💍💍");
+/// unpad_mut(&mut string, Alignment::Left, '💍');
+/// assert_eq!("elden ring", string);
+/// ```
+pub fn unpad_mut<S: MutableUnpad>(mut source: S, mode: Alignment, symbol: S::Symbol) {
+    source.unpad(mode, symbol);
+}
+
 #[cfg(test)]
 mod tests_wrappers {
     use super::*;
@@ -173,4 +368,76 @@ mod tests_wrappers {
         assert_eq!(Vec::from(&[false, true, false, true, true, false]), s);
         assert_eq!(6, s.len());
     }
+
+    #[test]
+    fn str_unpad() {
+        let source: &str = "!little big planet!!";
+        assert_eq!("little big planet", unpad(&source, Alignment::Center, '!'));
+    }
+
+    #[test]
+    fn mut_vec_unpad() {
+        let mut v: Vec<u8> = Vec::from(&[89u8, 89, 1, 2]);
+        unpad_mut(&mut v, Alignment::Right, 89u8);
+        assert_eq!(Vec::from(&[1u8, 2]), v);
+    }
+}
+
+#[cfg(all(test, feature = "macros"))]
+mod tests_macros {
+    use super::*;
+
+    #[test]
+    fn pad_single() {
+        let output = pad!("{:!^20}", "little big planet");
+        assert_eq!("!little big planet!!", output);
+    }
+
+    #[test]
+    fn pad_default_fill() {
+        let output = pad!("{:<6}|{:>6}", "ab", String::from("cd"));
+        assert_eq!("ab    |    cd", output);
+    }
+
+    #[test]
+    fn pad_multibyte_fill() {
+        let output = pad!("{:💣>8}", "uh oh");
+        assert_eq!("💣💣💣uh oh", output);
+        assert_eq!(17, output.len());
+    }
+
+    #[test]
+    fn pad_literals_and_escapes() {
+        let s = "ツ";
+        let output = pad!("{{{:-<3}}} = {:>2}", s, "1");
+        assert_eq!("{ツ--} =  1", output);
+    }
+
+    #[test]
+    fn pad_truncates() {
+        let output = pad!("{:<4}{:>4}", "sackboy", "sackgirl");
+        assert_eq!("sackgirl", output);
+    }
+
+    #[test]
+    fn pad_evaluates_args_once() {
+        let mut calls: usize = 0;
+        let mut next = || {
+            calls += 1;
+            String::from("x")
+        };
+        let output = pad!("{:.>3}", next());
+        assert_eq!("..x", output);
+        assert_eq!(1, calls);
+    }
+
+    #[test]
+    fn pad_no_placeholders() {
+        assert_eq!("praise the sun", pad!("praise the sun"));
+    }
+
+    #[test]
+    fn pad_empty() {
+        assert_eq!("", pad!(""));
+    }
 }
@@ -0,0 +1,332 @@
+use crate::alignment::Alignment;
+use crate::source::Source;
+use crate::width::Width;
+
+/// Specifies the target shape of a padded matrix, as [`Width`] strategies for each axis.
+///
+/// The `rows` strategy is resolved against the number of rows in the matrix, and the `cols`
+/// strategy is resolved against the length of the longest row. The default shape pads every
+/// row to the length of the longest row and keeps the number of rows.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// assert_eq!(Shape::new(2, 4), Shape::from((2, 4)));
+/// assert_eq!(Shape::new(Width::AtLeast(0), Width::AtLeast(0)), Shape::longest());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shape {
+    pub rows: Width,
+    pub cols: Width,
+}
+
+impl Shape {
+    /// Creates a new shape from the `rows` and `cols` width strategies.
+    pub fn new(rows: impl Into<Width>, cols: impl Into<Width>) -> Self {
+        Self {
+            rows: rows.into(),
+            cols: cols.into(),
+        }
+    }
+
+    /// Creates a shape which keeps the number of rows and pads every row to the longest row.
+    pub fn longest() -> Self {
+        Self::new(Width::AtLeast(0), Width::AtLeast(0))
+    }
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::longest()
+    }
+}
+
+impl From<(usize, usize)> for Shape {
+    fn from((rows, cols): (usize, usize)) -> Self {
+        Self::new(rows, cols)
+    }
+}
+
+/// A rectangular matrix stored as a flat row-major buffer, together with a mask marking
+/// which cells hold real items (`true`) and which cells hold padding (`false`).
+///
+/// Typically used to batch variable-length sequences, e.g., tokens for ML inference, where
+/// the mask is used as the attention mask.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaddedMatrix<T> {
+    pub data: Vec<T>,
+    pub mask: Vec<bool>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl<T> PaddedMatrix<T> {
+    /// Returns the items of the row at index `row`.
+    ///
+    /// # Panics
+    /// If `row` is out of bounds.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns the mask of the row at index `row`.
+    ///
+    /// # Panics
+    /// If `row` is out of bounds.
+    pub fn mask_row(&self, row: usize) -> &[bool] {
+        &self.mask[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+/// A trait for padding ragged 2D data, e.g., `Vec<Vec<T>>`, into a rectangular shape.
+///
+/// The padding is applied per axis:
+/// - `rows_mode` decides where padding rows are inserted (or which rows are truncated), where
+///   [`Alignment::Left`] keeps the content at the top and [`Alignment::Right`] at the bottom.
+/// - `cols_mode` decides how each row is padded (or truncated), exactly like [`Source::pad`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let batch: Vec<Vec<u32>> = vec![vec![101, 7, 102], vec![101, 102], vec![101]];
+/// let padded = batch.pad_matrix(Shape::longest(), Alignment::Left, Alignment::Left, 0u32);
+/// assert_eq!(vec![vec![101, 7, 102], vec![101, 102, 0], vec![101, 0, 0]], padded);
+///
+/// let padded = batch.pad_matrix((4, 2), Alignment::Right, Alignment::Left, 0u32);
+/// assert_eq!(vec![vec![0, 0], vec![101, 7], vec![101, 102], vec![101, 0]], padded);
+/// ```
+pub trait MatrixSource {
+    type Symbol;
+
+    /// Pads (or truncates) the matrix to the specified `shape` using the given `symbol`
+    /// according to the alignment modes of each axis.
+    fn pad_matrix(
+        &self,
+        shape: impl Into<Shape>,
+        rows_mode: Alignment,
+        cols_mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Vec<Vec<Self::Symbol>>;
+
+    /// Pads (or truncates) the matrix to the specified `shape` into a flat row-major
+    /// [`PaddedMatrix`], together with a mask marking the real items.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let batch: Vec<Vec<u32>> = vec![vec![1, 2, 3], vec![4]];
+    /// let padded = batch.pad_matrix_flat(Shape::longest(), Alignment::Left, Alignment::Right, 0u32);
+    /// assert_eq!((2, 3), (padded.rows, padded.cols));
+    /// assert_eq!(vec![1, 2, 3, 0, 0, 4], padded.data);
+    /// assert_eq!(vec![true, true, true, false, false, true], padded.mask);
+    /// ```
+    fn pad_matrix_flat(
+        &self,
+        shape: impl Into<Shape>,
+        rows_mode: Alignment,
+        cols_mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PaddedMatrix<Self::Symbol>;
+}
+
+/// Resolves the `shape` for the `rows` and returns the number of columns together with the
+/// index of the source row for each output row (`None` for padding rows).
+fn layout<T, R: AsRef<[T]>>(
+    rows: &[R],
+    shape: Shape,
+    rows_mode: Alignment,
+) -> (Vec<Option<usize>>, usize) {
+    let n_cols_longest: usize = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let n_cols: usize = shape.cols.resolve(n_cols_longest);
+
+    let indices: Vec<Option<usize>> = (0..rows.len()).map(Some).collect();
+    (indices.pad(shape.rows, rows_mode, None), n_cols)
+}
+
+fn pad_rows<T: Copy, R: AsRef<[T]>>(
+    rows: &[R],
+    shape: Shape,
+    rows_mode: Alignment,
+    cols_mode: Alignment,
+    symbol: T,
+) -> Vec<Vec<T>> {
+    let (indices, n_cols) = layout(rows, shape, rows_mode);
+    indices
+        .into_iter()
+        .map(|idx| match idx {
+            Some(idx) => rows[idx].as_ref().pad(n_cols, cols_mode, symbol),
+            None => vec![symbol; n_cols],
+        })
+        .collect()
+}
+
+fn pad_rows_flat<T: Copy, R: AsRef<[T]>>(
+    rows: &[R],
+    shape: Shape,
+    rows_mode: Alignment,
+    cols_mode: Alignment,
+    symbol: T,
+) -> PaddedMatrix<T> {
+    let (indices, n_cols) = layout(rows, shape, rows_mode);
+    let n_cells: usize = indices.len() * n_cols;
+    let mut data: Vec<T> = Vec::with_capacity(n_cells);
+    let mut mask: Vec<bool> = Vec::with_capacity(n_cells);
+
+    for idx in indices.iter() {
+        match idx {
+            Some(idx) => {
+                let row: &[T] = rows[*idx].as_ref();
+                row.pad_to_buffer(n_cols, cols_mode, symbol, &mut data);
+
+                let n_items: usize = row.len().min(n_cols);
+                let pads = cols_mode.pads(n_cols - n_items);
+                mask.resize(mask.len() + pads.left(), false);
+                mask.resize(mask.len() + n_items, true);
+                mask.resize(mask.len() + pads.right(), false);
+            }
+            None => {
+                data.resize(data.len() + n_cols, symbol);
+                mask.resize(mask.len() + n_cols, false);
+            }
+        }
+    }
+
+    PaddedMatrix {
+        data,
+        mask,
+        rows: indices.len(),
+        cols: n_cols,
+    }
+}
+
+impl<T> MatrixSource for Vec<Vec<T>>
+where
+    T: Clone + Copy + Sized,
+{
+    type Symbol = T;
+
+    fn pad_matrix(
+        &self,
+        shape: impl Into<Shape>,
+        rows_mode: Alignment,
+        cols_mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Vec<Vec<Self::Symbol>> {
+        pad_rows(self, shape.into(), rows_mode, cols_mode, symbol)
+    }
+
+    fn pad_matrix_flat(
+        &self,
+        shape: impl Into<Shape>,
+        rows_mode: Alignment,
+        cols_mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PaddedMatrix<Self::Symbol> {
+        pad_rows_flat(self, shape.into(), rows_mode, cols_mode, symbol)
+    }
+}
+
+impl<T> MatrixSource for &[&[T]]
+where
+    T: Clone + Copy + Sized,
+{
+    type Symbol = T;
+
+    fn pad_matrix(
+        &self,
+        shape: impl Into<Shape>,
+        rows_mode: Alignment,
+        cols_mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> Vec<Vec<Self::Symbol>> {
+        pad_rows(self, shape.into(), rows_mode, cols_mode, symbol)
+    }
+
+    /// Pads (or truncates) the matrix to the specified `shape` into a flat row-major
+    /// [`PaddedMatrix`], together with a mask marking the real items.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let rows: &[&[char]] = &[&['y', 'o'], &['r', 'n', 'o', 'o']];
+    /// let padded = rows.pad_matrix_flat((3, 3), Alignment::Center, Alignment::Left, '.');
+    /// assert_eq!(vec!['y', 'o', '.', 'r', 'n', 'o', '.', '.', '.'], padded.data);
+    /// assert_eq!(&[true, true, false], padded.mask_row(0));
+    /// assert_eq!(&[false, false, false], padded.mask_row(2));
+    /// ```
+    fn pad_matrix_flat(
+        &self,
+        shape: impl Into<Shape>,
+        rows_mode: Alignment,
+        cols_mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PaddedMatrix<Self::Symbol> {
+        pad_rows_flat(self, shape.into(), rows_mode, cols_mode, symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pad_matrix_longest() {
+        let m: Vec<Vec<u8>> = vec![vec![1], vec![2, 3, 4], vec![]];
+        let o = m.pad_matrix(Shape::default(), Alignment::Left, Alignment::Right, 0);
+        assert_eq!(vec![vec![0, 0, 1], vec![2, 3, 4], vec![0, 0, 0]], o);
+    }
+
+    #[test]
+    fn pad_matrix_fixed_truncates() {
+        let m: Vec<Vec<char>> = vec![vec!['a', 'b', 'c'], vec!['d'], vec!['e', 'f']];
+        let o = m.pad_matrix((2, 2), Alignment::Left, Alignment::Left, '-');
+        assert_eq!(vec![vec!['a', 'b'], vec!['d', '-']], o);
+
+        let o = m.pad_matrix((2, 2), Alignment::Right, Alignment::Right, '-');
+        assert_eq!(vec![vec!['-', 'd'], vec!['e', 'f']], o);
+    }
+
+    #[test]
+    fn pad_matrix_multiple_of() {
+        let m: Vec<Vec<u8>> = vec![vec![1, 2, 3, 4, 5]];
+        let shape = Shape::new(Width::MultipleOf(2), Width::MultipleOf(4));
+        let o = m.pad_matrix_flat(shape, Alignment::Left, Alignment::Left, 0);
+        assert_eq!((2, 8), (o.rows, o.cols));
+        assert_eq!(&[1, 2, 3, 4, 5, 0, 0, 0], o.row(0));
+        assert_eq!(&[0; 8], o.row(1));
+        assert_eq!(
+            &[true, true, true, true, true, false, false, false],
+            o.mask_row(0)
+        );
+        assert_eq!(&[false; 8], o.mask_row(1));
+    }
+
+    #[test]
+    fn pad_matrix_flat_center_mask() {
+        let m: Vec<Vec<u8>> = vec![vec![7], vec![7, 7, 7, 7]];
+        let o = m.pad_matrix_flat(Shape::longest(), Alignment::Left, Alignment::Center, 0);
+        assert_eq!(&[0, 7, 0, 0], o.row(0));
+        assert_eq!(&[false, true, false, false], o.mask_row(0));
+        assert_eq!(&[true; 4], o.mask_row(1));
+    }
+
+    #[test]
+    fn pad_matrix_flat_truncated_mask() {
+        let rows: &[&[u8]] = &[&[1, 2, 3], &[4]];
+        let o = rows.pad_matrix_flat((2, 2), Alignment::Left, Alignment::Right, 9);
+        assert_eq!(vec![2, 3, 9, 4], o.data);
+        assert_eq!(vec![true, true, false, true], o.mask);
+    }
+
+    #[test]
+    fn pad_matrix_empty() {
+        let m: Vec<Vec<u8>> = Vec::new();
+        let o = m.pad_matrix_flat(Shape::longest(), Alignment::Left, Alignment::Left, 0);
+        assert_eq!((0, 0), (o.rows, o.cols));
+        assert!(o.data.is_empty() && o.mask.is_empty());
+    }
+}
@@ -0,0 +1,646 @@
+use crate::alignment::Alignment;
+use crate::mutable_source::MutableSource;
+use crate::sink::PadSink;
+use crate::source::Source;
+use crate::width::Width;
+
+/// A way of measuring the width of strings, e.g., in chars, bytes, grapheme clusters, terminal
+/// columns, or pixels of a specific font, which can be plugged into [`MetricSource`].
+///
+/// Only [`WidthMetric::measure`] and [`WidthMetric::symbol_width`] are required. The provided
+/// offset methods walk the string char by char and hence assume that the width of a string is
+/// the sum of the widths of its chars, metrics where this does not hold (like
+/// `GraphemeWidth`) have to override them.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// // A (very) crude proportional font, where narrow letters are 1 unit and all others 2.
+/// struct Font;
+///
+/// impl WidthMetric for Font {
+///     fn measure(&self, s: &str) -> usize {
+///         s.chars().map(|c| self.symbol_width(c)).sum()
+///     }
+///
+///     fn symbol_width(&self, symbol: char) -> usize {
+///         if "iIl.' ".contains(symbol) { 1 } else { 2 }
+///     }
+/// }
+///
+/// assert_eq!(8, Font.measure("lilith"));
+/// assert_eq!("   lilith", "lilith".pad_with_metric(11, Alignment::Right, ' ', &Font));
+/// assert_eq!("lili", "lilith".truncate_to_fit_with_metric(5, Alignment::Left, &Font));
+/// ```
+pub trait WidthMetric {
+    /// Returns the width of the `s`.
+    fn measure(&self, s: &str) -> usize;
+
+    /// Returns the width of the pad `symbol`.
+    fn symbol_width(&self, symbol: char) -> usize;
+
+    /// Returns the byte offset at which the longest prefix of the `s` which is at most `width`
+    /// wide ends.
+    fn offset_from_start(&self, s: &str, width: usize) -> usize {
+        let mut n_units: usize = 0;
+        for (byte_offset, c) in s.char_indices() {
+            n_units += self.measure(&s[byte_offset..byte_offset + c.len_utf8()]);
+            if n_units > width {
+                return byte_offset;
+            }
+        }
+        s.len()
+    }
+
+    /// Returns the byte offset at which the longest suffix of the `s` which is at most `width`
+    /// wide starts.
+    fn offset_from_end(&self, s: &str, width: usize) -> usize {
+        let mut n_units: usize = 0;
+        for (byte_offset, c) in s.char_indices().rev() {
+            n_units += self.measure(&s[byte_offset..byte_offset + c.len_utf8()]);
+            if n_units > width {
+                return byte_offset + c.len_utf8();
+            }
+        }
+        0
+    }
+}
+
+/// Measures strings in utf8 chars, which is the width used by the [`Source`] and
+/// [`MutableSource`] impls of strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CharWidth;
+
+impl WidthMetric for CharWidth {
+    fn measure(&self, s: &str) -> usize {
+        s.chars().count()
+    }
+
+    fn symbol_width(&self, _symbol: char) -> usize {
+        1
+    }
+
+    fn offset_from_start(&self, s: &str, width: usize) -> usize {
+        s.char_indices()
+            .nth(width)
+            .map_or(s.len(), |(byte_offset, _)| byte_offset)
+    }
+
+    fn offset_from_end(&self, s: &str, width: usize) -> usize {
+        match width {
+            0 => s.len(),
+            _ => s
+                .char_indices()
+                .rev()
+                .nth(width - 1)
+                .map_or(0, |(byte_offset, _)| byte_offset),
+        }
+    }
+}
+
+/// Measures strings in UTF-8 encoded bytes, never splitting a UTF-8 sequence. Multi-byte pad
+/// symbols may leave some bytes unfilled, [`crate::ByteWidthSource`] rejects them to always
+/// pad to exact widths.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ByteWidth;
+
+impl WidthMetric for ByteWidth {
+    fn measure(&self, s: &str) -> usize {
+        s.len()
+    }
+
+    fn symbol_width(&self, symbol: char) -> usize {
+        symbol.len_utf8()
+    }
+
+    fn offset_from_start(&self, s: &str, width: usize) -> usize {
+        (0..=width.min(s.len()))
+            .rev()
+            .find(|&idx| s.is_char_boundary(idx))
+            .unwrap_or(0)
+    }
+
+    fn offset_from_end(&self, s: &str, width: usize) -> usize {
+        (s.len().saturating_sub(width)..=s.len())
+            .find(|&idx| s.is_char_boundary(idx))
+            .unwrap_or(s.len())
+    }
+}
+
+/// Measures strings in UTF-16 code units, never splitting a surrogate pair, e.g., for widths
+/// defined by JavaScript, Java or Windows APIs where astral chars like '🐉' count as 2.
+/// Astral pad symbols may leave a code unit unfilled, [`crate::Utf16WidthSource`] rejects them
+/// to always pad to exact widths.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Utf16Width;
+
+impl WidthMetric for Utf16Width {
+    fn measure(&self, s: &str) -> usize {
+        s.chars().map(char::len_utf16).sum()
+    }
+
+    fn symbol_width(&self, symbol: char) -> usize {
+        symbol.len_utf16()
+    }
+}
+
+/// Measures strings in extended grapheme clusters, i.e., user-perceived characters, such that
+/// `e` + U+0301 or a flag emoji count as 1 and are never split.
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GraphemeWidth;
+
+#[cfg(feature = "unicode-segmentation")]
+impl WidthMetric for GraphemeWidth {
+    fn measure(&self, s: &str) -> usize {
+        use unicode_segmentation::UnicodeSegmentation;
+        s.graphemes(true).count()
+    }
+
+    fn symbol_width(&self, _symbol: char) -> usize {
+        1
+    }
+
+    fn offset_from_start(&self, s: &str, width: usize) -> usize {
+        use unicode_segmentation::UnicodeSegmentation;
+        s.grapheme_indices(true)
+            .nth(width)
+            .map_or(s.len(), |(byte_offset, _)| byte_offset)
+    }
+
+    fn offset_from_end(&self, s: &str, width: usize) -> usize {
+        use unicode_segmentation::UnicodeSegmentation;
+        match width {
+            0 => s.len(),
+            _ => s
+                .grapheme_indices(true)
+                .rev()
+                .nth(width - 1)
+                .map_or(0, |(byte_offset, _)| byte_offset),
+        }
+    }
+}
+
+/// Measures strings in terminal display columns, where e.g. CJK and most emoji are 2 columns
+/// wide and combining marks are 0.
+#[cfg(feature = "unicode-width")]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ColumnWidth;
+
+#[cfg(feature = "unicode-width")]
+impl WidthMetric for ColumnWidth {
+    fn measure(&self, s: &str) -> usize {
+        unicode_width::UnicodeWidthStr::width(s)
+    }
+
+    fn symbol_width(&self, symbol: char) -> usize {
+        unicode_width::UnicodeWidthChar::width(symbol).unwrap_or(0)
+    }
+
+    /// Measures every prefix as a whole like [`ColumnWidth::measure`], as the columns of emoji
+    /// ZWJ sequences or chars followed by a variation selector are not the sum of their chars.
+    fn offset_from_start(&self, s: &str, width: usize) -> usize {
+        let mut ed_byte: usize = 0;
+        for (byte_offset, c) in s.char_indices() {
+            if self.measure(&s[..byte_offset + c.len_utf8()]) > width {
+                break;
+            }
+            ed_byte = byte_offset + c.len_utf8();
+        }
+        ed_byte
+    }
+
+    /// Measures every suffix as a whole like [`ColumnWidth::measure`], see
+    /// [`ColumnWidth::offset_from_start`].
+    fn offset_from_end(&self, s: &str, width: usize) -> usize {
+        let mut st_byte: usize = s.len();
+        for (byte_offset, _) in s.char_indices().rev() {
+            if self.measure(&s[byte_offset..]) > width {
+                break;
+            }
+            st_byte = byte_offset;
+        }
+        st_byte
+    }
+}
+
+/// Returns the byte range of the `s` which is kept when truncating it to at most `width` (as
+/// measured by the `metric`) according to the alignment `mode`.
+pub(crate) fn metric_range<M: WidthMetric + ?Sized>(
+    s: &str,
+    width: usize,
+    mode: Alignment,
+    metric: &M,
+) -> (usize, usize) {
+    match mode {
+        Alignment::Left | Alignment::Start => (0, metric.offset_from_start(s, width)),
+        Alignment::Right | Alignment::End => (metric.offset_from_end(s, width), s.len()),
+        Alignment::Center => {
+            let n_excess: usize = metric.measure(s).saturating_sub(width);
+            let st_byte: usize = metric.offset_from_start(s, n_excess / 2);
+            (
+                st_byte,
+                st_byte + metric.offset_from_start(&s[st_byte..], width),
+            )
+        }
+    }
+}
+
+/// Returns the byte range of the (possibly truncated) content of the `s` when padded to the
+/// `width` (as measured by the `metric`), together with the number of pad symbols on each side.
+fn metric_layout<M: WidthMetric + ?Sized>(
+    s: &str,
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: char,
+    metric: &M,
+) -> ((usize, usize), usize, usize) {
+    let n_units: usize = metric.measure(s);
+    let width: usize = width.into().resolve(n_units);
+    let (byte_range, n_units_content): ((usize, usize), usize) = if width < n_units {
+        let (st_byte, ed_byte) = metric_range(s, width, mode, metric);
+        ((st_byte, ed_byte), metric.measure(&s[st_byte..ed_byte]))
+    } else {
+        ((0, s.len()), n_units)
+    };
+
+    let pads = mode.pads(width.saturating_sub(n_units_content));
+    match metric.symbol_width(symbol) {
+        0 => (byte_range, 0, 0),
+        n => (byte_range, pads.left() / n, pads.right() / n),
+    }
+}
+
+/// Pads (or truncates) the `s` to the specified `width` (as measured by the `metric`) according
+/// to the alignment `mode`, appending the result to the `sink`.
+///
+/// This is the implementation behind every padding method of strings, which use [`CharWidth`]
+/// unless another metric is specified.
+pub(crate) fn pad_metric_to_sink<M, K>(
+    s: &str,
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: char,
+    metric: &M,
+    sink: &mut K,
+) where
+    M: WidthMetric + ?Sized,
+    K: PadSink<char, str> + ?Sized,
+{
+    let ((st_byte, ed_byte), n_left, n_right) = metric_layout(s, width, mode, symbol, metric);
+    sink.reserve(ed_byte - st_byte + (n_left + n_right) * symbol.len_utf8());
+    sink.push_run(symbol, n_left);
+    sink.push_slice(&s[st_byte..ed_byte]);
+    sink.push_run(symbol, n_right);
+}
+
+/// Pads (or truncates) the `s` in-place to the specified `width` (as measured by the `metric`)
+/// according to the alignment `mode`, see [`pad_metric_to_sink`].
+pub(crate) fn pad_metric_in_place<M: WidthMetric + ?Sized>(
+    s: &mut String,
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: char,
+    metric: &M,
+) {
+    let ((st_byte, ed_byte), n_left, n_right) = metric_layout(s, width, mode, symbol, metric);
+    s.truncate(ed_byte);
+    s.replace_range(..st_byte, "");
+    push_pads_in_place(s, symbol, n_left, n_right);
+}
+
+/// Inserts `n_left` symbols at the start and appends `n_right` symbols to the end of the `s`,
+/// using a temporary allocation if there are any symbols to insert.
+#[cfg(not(feature = "enable_unsafe"))]
+fn push_pads_in_place(s: &mut String, symbol: char, n_left: usize, n_right: usize) {
+    if n_left > 0 {
+        let mut padded = String::with_capacity(s.len() + (n_left + n_right) * symbol.len_utf8());
+        padded.extend(std::iter::repeat_n(symbol, n_left));
+        padded.push_str(s);
+        *s = padded;
+    }
+    s.extend(std::iter::repeat_n(symbol, n_right));
+}
+
+/// Inserts `n_left` symbols at the start and appends `n_right` symbols to the end of the `s`,
+/// without any temporary allocations.
+#[cfg(feature = "enable_unsafe")]
+fn push_pads_in_place(s: &mut String, symbol: char, n_left: usize, n_right: usize) {
+    if n_left + n_right == 0 {
+        return;
+    }
+
+    let n_bytes_symbol: usize = symbol.len_utf8();
+    let n_bytes_original: usize = s.len();
+    let n_bytes_l_pad: usize = n_left * n_bytes_symbol;
+    let n_bytes_r_pad: usize = n_right * n_bytes_symbol;
+    s.reserve_exact(n_bytes_l_pad + n_bytes_r_pad);
+
+    // SAFETY: the capacity was reserved above, the original bytes are moved behind the left
+    // padding, and every other byte is overwritten with the UTF-8 encoded symbol.
+    unsafe {
+        let buf: &mut Vec<u8> = s.as_mut_vec();
+        buf.set_len(n_bytes_original + n_bytes_l_pad + n_bytes_r_pad);
+        buf.copy_within(..n_bytes_original, n_bytes_l_pad);
+
+        for idx in 0..n_left {
+            symbol.encode_utf8(&mut buf[idx * n_bytes_symbol..]);
+        }
+        let st_byte_r_pad: usize = n_bytes_l_pad + n_bytes_original;
+        for idx in 0..n_right {
+            symbol.encode_utf8(&mut buf[st_byte_r_pad + idx * n_bytes_symbol..]);
+        }
+    }
+}
+
+/// A companion trait to [`Source`] for string types which pads (or truncates) to a width
+/// measured by any [`WidthMetric`] instead of utf8 chars.
+///
+/// When the width of the pad `symbol` does not evenly divide the width left to pad on a side,
+/// that side is padded with as many symbols as fit, so the output may be narrower than `width`.
+/// Zero-width symbols are never padded.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// // Equivalent to `Source::pad`.
+/// assert_eq!("ツ***", "ツ".pad_with_metric(4, Alignment::Left, '*', &CharWidth));
+///
+/// // 'ツ' is 3 bytes.
+/// assert_eq!("ツ*", "ツ".pad_with_metric(4, Alignment::Left, '*', &ByteWidth));
+/// assert_eq!("ab", "abツ".truncate_to_fit_with_metric(4, Alignment::Left, &ByteWidth));
+/// ```
+pub trait MetricSource: Source<Symbol = char> {
+    /// Truncates the string to at most the specified `width` (as measured by the `metric`)
+    /// according to the specified alignment `mode`.
+    fn truncate_to_fit_with_metric<M: WidthMetric + ?Sized>(
+        &self,
+        width: usize,
+        mode: Alignment,
+        metric: &M,
+    ) -> &str;
+
+    /// Pads (or truncates) the string to the specified `width` (as measured by the `metric`)
+    /// using the given `symbol` according to the specified alignment `mode`.
+    fn pad_with_metric<M: WidthMetric + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        metric: &M,
+    ) -> String;
+
+    /// Pads (or truncates) the string to the specified `width` (as measured by the `metric`)
+    /// using the given `symbol` according to the specified alignment `mode`, appending the
+    /// result to the `buffer`.
+    fn pad_to_buffer_with_metric<M: WidthMetric + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        metric: &M,
+        buffer: &mut String,
+    );
+}
+
+impl<S: Source<Symbol = char> + AsRef<str> + ?Sized> MetricSource for S {
+    fn truncate_to_fit_with_metric<M: WidthMetric + ?Sized>(
+        &self,
+        width: usize,
+        mode: Alignment,
+        metric: &M,
+    ) -> &str {
+        let s: &str = self.as_ref();
+        let (st_byte, ed_byte) = metric_range(s, width, mode, metric);
+        &s[st_byte..ed_byte]
+    }
+
+    fn pad_with_metric<M: WidthMetric + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        metric: &M,
+    ) -> String {
+        let mut output = String::new();
+        pad_metric_to_sink(self.as_ref(), width, mode, symbol, metric, &mut output);
+        output
+    }
+
+    fn pad_to_buffer_with_metric<M: WidthMetric + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        metric: &M,
+        buffer: &mut String,
+    ) {
+        pad_metric_to_sink(self.as_ref(), width, mode, symbol, metric, buffer);
+    }
+}
+
+/// A companion trait to [`MutableSource`] which pads (or truncates) a string in-place to a width
+/// measured by any [`WidthMetric`], see [`MetricSource`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut s = String::from("ツツ");
+/// (&mut s).pad_with_metric(5, Alignment::Center, '-', &ByteWidth);
+/// assert_eq!("-ツ-", s);
+/// ```
+pub trait MutableMetricSource: MutableSource<Symbol = char> {
+    /// Pads (or truncates) the string in-place to the specified `width` (as measured by the
+    /// `metric`) using the given `symbol` according to the specified alignment `mode`.
+    fn pad_with_metric<M: WidthMetric + ?Sized>(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        metric: &M,
+    );
+}
+
+impl MutableMetricSource for &mut String {
+    fn pad_with_metric<M: WidthMetric + ?Sized>(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        metric: &M,
+    ) {
+        pad_metric_in_place(self, width, mode, symbol, metric);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_width_matches_source() {
+        let s: &str = "🐉malenia";
+        for mode in [Alignment::Left, Alignment::Right, Alignment::Center] {
+            for width in 0..12 {
+                assert_eq!(
+                    s.pad(width, mode, '*'),
+                    s.pad_with_metric(width, mode, '*', &CharWidth)
+                );
+                assert_eq!(
+                    s.truncate_to_fit(width, mode),
+                    s.truncate_to_fit_with_metric(width, mode, &CharWidth)
+                );
+
+                let mut in_place = String::from(s);
+                (&mut in_place).pad(width, mode, '*');
+                assert_eq!(s.pad(width, mode, '*'), in_place);
+            }
+            assert_eq!("", s.pad(0, mode, '*'));
+        }
+    }
+
+    #[test]
+    fn byte_width_never_splits() {
+        let s: &str = "aツb";
+        assert_eq!(
+            "a",
+            s.truncate_to_fit_with_metric(3, Alignment::Left, &ByteWidth)
+        );
+        assert_eq!(
+            "b",
+            s.truncate_to_fit_with_metric(3, Alignment::Right, &ByteWidth)
+        );
+        assert_eq!(
+            "ツ",
+            s.truncate_to_fit_with_metric(3, Alignment::Center, &ByteWidth)
+        );
+        assert_eq!(
+            "aツb",
+            s.truncate_to_fit_with_metric(9, Alignment::Center, &ByteWidth)
+        );
+    }
+
+    #[test]
+    fn default_offsets_are_additive() {
+        struct Double;
+
+        impl WidthMetric for Double {
+            fn measure(&self, s: &str) -> usize {
+                2 * s.chars().count()
+            }
+
+            fn symbol_width(&self, _symbol: char) -> usize {
+                2
+            }
+        }
+
+        assert_eq!(2, Double.offset_from_start("gwyn", 5));
+        assert_eq!(2, Double.offset_from_end("gwyn", 5));
+        assert_eq!(
+            "wy",
+            "gwyn".truncate_to_fit_with_metric(4, Alignment::Center, &Double)
+        );
+        // The 3 units left to pad only fit a single symbol of width 2.
+        assert_eq!(
+            "gwyn.",
+            "gwyn".pad_with_metric(11, Alignment::Left, '.', &Double)
+        );
+    }
+
+    #[test]
+    fn mutable_pad_with_metric() {
+        let mut s = String::from("ranni");
+        (&mut s).pad_with_metric(Width::AtLeast(7), Alignment::Right, ' ', &CharWidth);
+        assert_eq!("  ranni", s);
+        (&mut s).pad_with_metric(3, Alignment::Left, ' ', &ByteWidth);
+        assert_eq!("  r", s);
+    }
+
+    #[test]
+    fn utf16_width() {
+        let s: &str = "a🐉b";
+        assert_eq!(4, Utf16Width.measure(s));
+        assert_eq!(
+            "a",
+            s.truncate_to_fit_with_metric(2, Alignment::Left, &Utf16Width)
+        );
+        assert_eq!(
+            "🐉b",
+            s.truncate_to_fit_with_metric(3, Alignment::Right, &Utf16Width)
+        );
+        assert_eq!(
+            "🐉a🐉b",
+            s.pad_with_metric(7, Alignment::Right, '🐉', &Utf16Width)
+        );
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn grapheme_width() {
+        let s: &str = "e\u{301}lden";
+        assert_eq!(5, GraphemeWidth.measure(s));
+        assert_eq!(
+            "e\u{301}l",
+            s.truncate_to_fit_with_metric(2, Alignment::Left, &GraphemeWidth)
+        );
+        assert_eq!(
+            "en",
+            s.truncate_to_fit_with_metric(2, Alignment::Right, &GraphemeWidth)
+        );
+        assert_eq!(
+            "-e\u{301}lden",
+            s.pad_with_metric(6, Alignment::Right, '-', &GraphemeWidth)
+        );
+    }
+
+    #[cfg(feature = "unicode-width")]
+    #[test]
+    fn column_width() {
+        let s: &str = "ツツa";
+        assert_eq!(5, ColumnWidth.measure(s));
+        assert_eq!(
+            "ツ",
+            s.truncate_to_fit_with_metric(3, Alignment::Left, &ColumnWidth)
+        );
+        assert_eq!(
+            "ツa",
+            s.truncate_to_fit_with_metric(3, Alignment::Right, &ColumnWidth)
+        );
+        assert_eq!(
+            "ツツa ",
+            s.pad_with_metric(6, Alignment::Left, ' ', &ColumnWidth)
+        );
+        // A 2 column wide symbol only fits once into 3 columns.
+        assert_eq!(
+            "ツツツa",
+            s.pad_with_metric(8, Alignment::Right, 'ツ', &ColumnWidth)
+        );
+    }
+
+    #[cfg(feature = "unicode-width")]
+    #[test]
+    fn column_width_zwj_sequence() {
+        // The ZWJ sequence is 2 columns wide, rather than the 4 columns of its two emoji.
+        let s: &str = "a👩\u{200d}💻b";
+        assert_eq!(4, ColumnWidth.measure(s));
+        assert_eq!(
+            "a👩\u{200d}💻",
+            s.truncate_to_fit_with_metric(3, Alignment::Left, &ColumnWidth)
+        );
+        assert_eq!(
+            "👩\u{200d}💻b",
+            s.truncate_to_fit_with_metric(3, Alignment::Right, &ColumnWidth)
+        );
+        assert_eq!(
+            "👩\u{200d}💻",
+            s.truncate_to_fit_with_metric(2, Alignment::Center, &ColumnWidth)
+        );
+        assert_eq!(
+            "a👩\u{200d}💻b.",
+            s.pad_with_metric(5, Alignment::Left, '.', &ColumnWidth)
+        );
+    }
+}
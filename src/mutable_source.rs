@@ -1,4 +1,7 @@
 use crate::alignment::Alignment;
+use crate::metric::{CharWidth, WidthMetric, pad_metric_in_place};
+use crate::outcome::{MutableOutcomeSource, PadOutcome};
+use crate::width::Width;
 
 /// A trait representing a mutable, width-aware data buffer that can be padded (and truncated).
 ///
@@ -19,7 +22,11 @@ pub trait MutableSource {
     type Symbol;
     type Buffer;
 
-    fn pad(&mut self, width: usize, mode: Alignment, symbol: Self::Symbol);
+    /// Pads (or truncates) the buffer in-place to the specified `width` using the given `symbol`
+    /// according to the specified alignment `mode`.
+    ///
+    /// The `width` is either an exact `usize` or any [`Width`] strategy.
+    fn pad(&mut self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol);
 }
 
 impl MutableSource for &mut String {
@@ -31,11 +38,13 @@ impl MutableSource for &mut String {
     /// If the string is longer than `width` (in utf8 chars), it will be truncated according to the `mode`:
     /// - [`Alignment::Left`]: truncates from the right.
     /// - [`Alignment::Right`]: truncates from the left.
-    /// - [`Alignment::Center`]: trims equally from both ends (extra char trimmed from the left if number of chars to trim is odd).
+    /// - [`Alignment::Center`]: trims equally from both ends (extra char trimmed from the right if number of chars to trim is odd).
     ///
     /// If the buffer is shorter than `width`, it will be padded using the specified `symbol`:
     /// - Padding is distributed based on alignment: left, right, or center (extra symbol on the right if number of chars to pad is odd).
-    /// - The implementation performs two temporary allocations to construct the padded version (much more efficient than performing repeated [`insert()`] calls).
+    /// - The implementation performs a single temporary allocation to construct the padded version (much more efficient than performing repeated [`insert()`] calls).
+    ///   If compiled with the `enable_unsafe` feature flag, no heap allocations are performed
+    ///   instead, by moving the content with [`set_len()`] and [`copy_within()`].
     ///
     /// The result replaces the original string.
     ///
@@ -48,181 +57,42 @@ impl MutableSource for &mut String {
     /// (&mut s).pad(width, Alignment::Center, '¡');  // "¡Visa Vid Vindens Ängar¡¡"
     ///
     /// assert_eq!(25, s.chars().count());
+    ///
+    /// let mut s = String::from("sackboy");
+    /// (&mut s).pad(11, Alignment::Right, '-');
+    /// assert_eq!("----sackboy", s);
     /// ```
     /// [`insert()`]: String::insert()
-    #[cfg(not(feature = "enable_unsafe"))]
-    fn pad(&mut self, width: usize, mode: Alignment, symbol: Self::Symbol) {
-        let n_chars_original: usize = self.chars().count();
-        if width < n_chars_original {
-            match mode {
-                Alignment::Left => {
-                    let byte_offset_trunc: usize = self
-                        .char_indices()
-                        .nth(width)
-                        .map(|(byte_offset, _)| byte_offset)
-                        .expect("the String did not contain enough chars!");
-                    self.truncate(byte_offset_trunc);
-                }
-                Alignment::Right => {
-                    let byte_st = self
-                        .char_indices()
-                        .rev()
-                        .nth(width - 1)
-                        .map(|(byte_offset, _)| byte_offset)
-                        .expect("the String did not contain enough chars!");
-                    self.replace_range(..byte_st, "");
-                }
-                Alignment::Center => {
-                    let st_idx: usize = (n_chars_original - width) / 2;
-                    let ed_idx: usize = st_idx + width;
-
-                    let mut st_byte: usize = 0;
-                    let mut ed_byte: usize = self.len();
-
-                    for (idx, (byte_offset, _)) in self.char_indices().enumerate() {
-                        if idx == st_idx {
-                            st_byte = byte_offset;
-                        }
-                        if idx == ed_idx {
-                            ed_byte = byte_offset;
-                            break;
-                        }
-                    }
-
-                    self.replace_range(..st_byte, "");
-                    self.truncate(ed_byte - st_byte);
-                }
-            };
-            return;
-        }
-
-        let n_chars_diff: usize = width - n_chars_original;
-        if n_chars_diff == 0 {
-            return;
-        }
-
-        let pads = mode.pads(n_chars_diff);
-        let mut new_s: String = std::iter::repeat_n(symbol, pads.left()).collect();
-
-        new_s.push_str(self);
-        new_s.push_str(&std::iter::repeat_n(symbol, pads.right()).collect::<String>());
-        **self = new_s;
+    /// [`set_len()`]: Vec::set_len()
+    /// [`copy_within()`]: https://doc.rust-lang.org/std/primitive.slice.html#method.copy_within
+    fn pad(&mut self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) {
+        pad_metric_in_place(self, width, mode, symbol, &CharWidth);
     }
+}
 
-    /// Pads or truncates the string to match the specified width with a given alignment.
-    ///
-    /// If the string is longer than `width` (in utf8 chars), it will be truncated according to the `mode`:
-    /// - [`Alignment::Left`]: truncates from the right.
-    /// - [`Alignment::Right`]: truncates from the left.
-    /// - [`Alignment::Center`]: trims equally from both ends (extra char trimmed from the left if number of chars to trim is odd).
-    ///
-    /// If the buffer is shorter than `width`, it will be padded using the specified `symbol`:
-    /// - Padding is distributed based on alignment: left, right, or center (extra symbol on the right if number of chars to pad is odd).
-    /// - This implementation performs no heap allocations to construct the padded version (but introduces `unsafe` code).
-    ///
-    /// The result replaces the original string.
-    ///
-    /// # Safety
-    /// This implementation makes use of the [`set_len()`] and [`copy_within()`] methods to directly
-    /// modify the contents of the String buffer without having to perform any extra allocations.
-    ///
-    /// This greatly improves performance when padding large strings, truncating performance should be unchanged.
+impl MutableOutcomeSource for &mut String {
+    /// Pads or truncates the string in-place like [`MutableSource::pad`], additionally returning
+    /// a [`PadOutcome`].
     ///
     /// # Examples
     /// ```
     /// use padder::*;
     ///
-    /// let mut s = String::from("sackboy");
-    /// let width: usize = 11;
-    /// (&mut s).pad(width, Alignment::Right, '-');  // "----sackboy
-    ///
-    /// let mut expected = String::from("----sackboy");
-    ///
-    /// assert_eq!(11, s.chars().count());
-    /// assert_eq!(expected.len(), s.len());
-    /// assert_eq!(expected, s);
+    /// let mut s = String::from("malenia");
+    /// let outcome = (&mut s).pad_with_outcome(10, Alignment::Center, 'ツ');
+    /// assert_eq!("ツmaleniaツツ", s);
+    /// assert_eq!("malenia", &s[outcome.bytes]);
     /// ```
-    /// [`set_len()`]: Vec::set_len()
-    /// [`copy_within()`]: https://doc.rust-lang.org/std/primitive.slice.html#method.copy_within
-    #[cfg(feature = "enable_unsafe")]
-    fn pad(&mut self, width: usize, mode: Alignment, symbol: Self::Symbol) {
-        let n_chars_original: usize = self.chars().count();
-        let n_bytes_original: usize = self.len();
-
-        if width < n_chars_original {
-            match mode {
-                Alignment::Left => {
-                    let byte_offset_trunc: usize = self
-                        .char_indices()
-                        .nth(width)
-                        .map(|(byte_offset, _)| byte_offset)
-                        .expect("the String did not contain enough chars!");
-                    self.truncate(byte_offset_trunc);
-                }
-                Alignment::Right => {
-                    let st_byte: usize = self
-                        .char_indices()
-                        .rev()
-                        .nth(width - 1)
-                        .map(|(byte_offset, _)| byte_offset)
-                        .expect("the String did not contain enough chars!");
-                    self.replace_range(..st_byte, "");
-                }
-                Alignment::Center => {
-                    let st_idx: usize = (n_chars_original - width) / 2;
-                    let ed_idx: usize = st_idx + width;
-
-                    let mut st_byte: usize = 0;
-                    let mut ed_byte: usize = self.len();
-
-                    for (idx, (byte_offset, _)) in self.char_indices().enumerate() {
-                        if idx == st_idx {
-                            st_byte = byte_offset;
-                            continue;
-                        }
-                        if idx == ed_idx {
-                            ed_byte = byte_offset;
-                            break;
-                        }
-                    }
-
-                    self.replace_range(..st_byte, "");
-                    self.truncate(ed_byte - st_byte);
-                }
-            }
-            return;
-        }
-
-        let n_chars_diff: usize = width - n_chars_original;
-        if n_chars_diff == 0 {
-            return;
-        }
-
-        let n_bytes_symbol: usize = symbol.len_utf8();
-        let n_bytes_diff: usize = n_chars_diff * n_bytes_symbol;
-
-        let pads = mode.pads(n_chars_diff);
-        let n_bytes_l_pad = pads.left() * n_bytes_symbol;
-        let n_bytes_r_pad = pads.right() * n_bytes_symbol;
-
-        self.reserve_exact(n_bytes_diff);
-
-        unsafe {
-            let buf: &mut Vec<u8> = self.as_mut_vec();
-            buf.set_len(n_bytes_original + n_bytes_diff);
-            buf.copy_within(..(n_bytes_original + n_bytes_r_pad), n_bytes_l_pad);
-
-            let mut byte_offset_l: usize = 0;
-            for _ in 0..pads.left() {
-                byte_offset_l += symbol.encode_utf8(&mut buf[byte_offset_l..]).len();
-            }
-
-            let mut byte_offset_r: usize = buf.len();
-            for _ in 0..pads.right() {
-                byte_offset_r -= n_bytes_symbol;
-                symbol.encode_utf8(&mut buf[byte_offset_r..]);
-            }
-        }
+    fn pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PadOutcome {
+        let n_chars_original: usize = CharWidth.measure(self);
+        let width: usize = width.into().resolve(n_chars_original);
+        MutableSource::pad(self, width, mode, symbol);
+        PadOutcome::new(n_chars_original, width, mode).with_bytes(symbol.len_utf8(), self.len())
     }
 }
 
@@ -292,13 +162,14 @@ where
     /// ```
     ///
     /// [`insert()`]: Vec::insert()
-    fn pad(&mut self, width: usize, mode: Alignment, symbol: Self::Symbol) {
+    fn pad(&mut self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) {
+        let width: usize = width.into().resolve(self.len());
         if width < self.len() {
             match mode {
-                Alignment::Left => {
+                Alignment::Left | Alignment::Start => {
                     self.truncate(width);
                 }
-                Alignment::Right => {
+                Alignment::Right | Alignment::End => {
                     let byte_offset_drain: usize = self.len() - width;
                     self.drain(..byte_offset_drain);
                 }
@@ -325,6 +196,26 @@ where
     }
 }
 
+impl<T> MutableOutcomeSource for &mut Vec<T>
+where
+    T: Copy + Sized,
+{
+    /// Pads or truncates the vector in-place like [`MutableSource::pad`], additionally returning
+    /// a [`PadOutcome`].
+    fn pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PadOutcome {
+        let n_items_original: usize = self.len();
+        let width: usize = width.into().resolve(n_items_original);
+        MutableSource::pad(self, width, mode, symbol);
+        PadOutcome::new(n_items_original, width, mode)
+            .with_bytes(size_of::<T>(), self.len() * size_of::<T>())
+    }
+}
+
 #[cfg(test)]
 mod tests_string {
     use super::*;
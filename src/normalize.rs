@@ -0,0 +1,193 @@
+use std::borrow::Cow;
+
+use unicode_normalization::{IsNormalized, UnicodeNormalization};
+
+use crate::alignment::Alignment;
+use crate::mutable_source::MutableSource;
+use crate::source::Source;
+use crate::width::Width;
+
+/// The Unicode normalization form applied to a string before its width is measured.
+///
+/// - `Nfc`: canonical composition, e.g., `e` + U+0301 becomes `é` (U+00E9).
+/// - `Nfkc`: compatibility composition, which additionally folds compatibility characters,
+///   e.g., the ligature `ﬁ` becomes `fi` and the full-width `Ａ` becomes `A`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Normalization {
+    #[default]
+    Nfc,
+    Nfkc,
+}
+
+impl Normalization {
+    /// Returns the `s` in the normalization form, borrowing it if it already is normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let decomposed = "e\u{301}";
+    /// assert_eq!("\u{e9}", Normalization::Nfc.normalize(decomposed));
+    /// assert_eq!("fi", Normalization::Nfkc.normalize("\u{fb01}"));
+    /// ```
+    pub fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::Nfc => match unicode_normalization::is_nfc_quick(s.chars()) {
+                IsNormalized::Yes => Cow::Borrowed(s),
+                _ => Cow::Owned(s.nfc().collect()),
+            },
+            Self::Nfkc => match unicode_normalization::is_nfkc_quick(s.chars()) {
+                IsNormalized::Yes => Cow::Borrowed(s),
+                _ => Cow::Owned(s.nfkc().collect()),
+            },
+        }
+    }
+}
+
+/// A companion trait to [`Source`] for string types which normalizes the string before padding
+/// it, such that the padded width is the same regardless of the composition form of the input.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let composed = "caf\u{e9}";
+/// let decomposed = "cafe\u{301}";
+/// assert_ne!(composed.pad(6, Alignment::Left, '.'), decomposed.pad(6, Alignment::Left, '.'));
+/// assert_eq!(
+///     composed.pad_normalized(6, Alignment::Left, '.', Normalization::Nfc),
+///     decomposed.pad_normalized(6, Alignment::Left, '.', Normalization::Nfc),
+/// );
+/// ```
+pub trait NormalizedSource: Source<Symbol = char> {
+    /// Normalizes the string into the `form` and pads (or truncates) it like [`Source::pad`].
+    fn pad_normalized(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        form: Normalization,
+    ) -> String;
+
+    /// Normalizes the string into the `form` and pads (or truncates) it into the `buffer` like
+    /// [`Source::pad_to_buffer`].
+    fn pad_to_buffer_normalized(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        form: Normalization,
+        buffer: &mut String,
+    );
+}
+
+impl<S: Source<Symbol = char> + AsRef<str> + ?Sized> NormalizedSource for S {
+    fn pad_normalized(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        form: Normalization,
+    ) -> String {
+        let normalized: Cow<'_, str> = form.normalize(self.as_ref());
+        normalized.as_ref().pad(width, mode, symbol)
+    }
+
+    fn pad_to_buffer_normalized(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        form: Normalization,
+        buffer: &mut String,
+    ) {
+        let normalized: Cow<'_, str> = form.normalize(self.as_ref());
+        normalized
+            .as_ref()
+            .pad_to_buffer(width, mode, symbol, buffer);
+    }
+}
+
+/// A companion trait to [`MutableSource`] which normalizes the string in-place before padding
+/// it, see [`NormalizedSource`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut s = String::from("n\u{303}");
+/// (&mut s).pad_normalized(3, Alignment::Center, '*', Normalization::Nfc);
+/// assert_eq!("*\u{f1}*", s);
+/// ```
+pub trait MutableNormalizedSource: MutableSource {
+    /// Normalizes the string into the `form` in-place and pads (or truncates) it like
+    /// [`MutableSource::pad`].
+    fn pad_normalized(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        form: Normalization,
+    );
+}
+
+impl MutableNormalizedSource for &mut String {
+    fn pad_normalized(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        form: Normalization,
+    ) {
+        if let Cow::Owned(normalized) = form.normalize(self) {
+            **self = normalized;
+        }
+        MutableSource::pad(self, width, mode, symbol);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_borrows_normalized() {
+        assert!(matches!(
+            Normalization::Nfc.normalize("ashen one"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            Normalization::Nfkc.normalize("\u{e9}"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn pad_normalized_nfkc() {
+        let s = String::from("\u{ff21}\u{fb01}");
+        assert_eq!(
+            "--Afi",
+            s.pad_normalized(5, Alignment::Right, '-', Normalization::Nfkc)
+        );
+    }
+
+    #[test]
+    fn pad_to_buffer_normalized_truncates_composed() {
+        let mut buffer = String::new();
+        "a\u{301}e\u{301}i\u{301}".pad_to_buffer_normalized(
+            2,
+            Alignment::Left,
+            ' ',
+            Normalization::Nfc,
+            &mut buffer,
+        );
+        assert_eq!("\u{e1}\u{e9}", buffer);
+    }
+
+    #[test]
+    fn mutable_pad_normalized() {
+        let mut s = String::from("cafe\u{301}");
+        (&mut s).pad_normalized(6, Alignment::Left, '.', Normalization::Nfc);
+        assert_eq!("caf\u{e9}..", s);
+    }
+}
@@ -0,0 +1,377 @@
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::path::Path;
+
+#[cfg(unix)]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+use crate::alignment::Alignment;
+use crate::outcome::{OutcomeSource, PadOutcome};
+use crate::path::elide_path;
+#[cfg(unix)]
+use crate::path::elide_path_bytes;
+use crate::source::Source;
+use crate::truncation::{TruncatingSource, Truncation};
+use crate::width::Width;
+
+/// Returns the number of units in the `s`, where a unit is a utf8 char if the `s` is valid
+/// UTF-8, otherwise every invalid byte is counted as a unit of its own (on Unix).
+///
+/// On other platforms, non-UTF-8 data is counted on its lossy conversion.
+fn n_units(s: &OsStr) -> usize {
+    match s.to_str() {
+        Some(s) => s.chars().count(),
+        #[cfg(unix)]
+        None => s
+            .as_bytes()
+            .utf8_chunks()
+            .map(|chunk| chunk.valid().chars().count() + chunk.invalid().len())
+            .sum(),
+        #[cfg(not(unix))]
+        None => s.to_string_lossy().chars().count(),
+    }
+}
+
+/// Returns the byte offset of every unit in `bytes`, followed by the length of `bytes`.
+#[cfg(unix)]
+fn unit_offsets(bytes: &[u8]) -> Vec<usize> {
+    let mut offsets: Vec<usize> = Vec::with_capacity(bytes.len() + 1);
+    let mut byte_offset: usize = 0;
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            offsets.push(byte_offset);
+            byte_offset += c.len_utf8();
+        }
+        for _ in chunk.invalid() {
+            offsets.push(byte_offset);
+            byte_offset += 1;
+        }
+    }
+    offsets.push(byte_offset);
+    offsets
+}
+
+/// Truncates the `s` to the specified `width` (in units) according to the alignment `mode`.
+fn truncate_os_str(s: &OsStr, width: usize, mode: Alignment) -> Cow<'_, OsStr> {
+    if let Some(s) = s.to_str() {
+        return Cow::Borrowed(OsStr::new(s.truncate_to_fit(width, mode)));
+    }
+
+    #[cfg(unix)]
+    {
+        let bytes: &[u8] = s.as_bytes();
+        let offsets: Vec<usize> = unit_offsets(bytes);
+        let n_units: usize = offsets.len() - 1;
+        let st_idx: usize = match mode {
+            Alignment::Left | Alignment::Start => 0,
+            Alignment::Right | Alignment::End => n_units - width,
+            Alignment::Center => (n_units - width) / 2,
+        };
+        Cow::Borrowed(OsStr::from_bytes(
+            &bytes[offsets[st_idx]..offsets[st_idx + width]],
+        ))
+    }
+
+    #[cfg(not(unix))]
+    {
+        let lossy = s.to_string_lossy();
+        let truncated: &str = lossy.as_ref().truncate_to_fit(width, mode);
+        Cow::Owned(OsString::from(truncated))
+    }
+}
+
+/// Truncates the `path` to the specified `width` (in units) by eliding whole components from
+/// the middle of it, always keeping the file name.
+///
+/// Non-UTF-8 paths are elided over their raw bytes on Unix, and over their lossy conversion on
+/// other platforms.
+fn truncate_path(path: &OsStr, width: usize, mode: Alignment) -> Cow<'_, OsStr> {
+    if let Some(s) = path.to_str() {
+        return match elide_path(s, width, mode) {
+            Cow::Borrowed(s) => Cow::Borrowed(OsStr::new(s)),
+            Cow::Owned(s) => Cow::Owned(OsString::from(s)),
+        };
+    }
+
+    #[cfg(unix)]
+    {
+        match elide_path_bytes(path.as_bytes(), width, mode) {
+            Cow::Borrowed(bytes) => Cow::Borrowed(OsStr::from_bytes(bytes)),
+            Cow::Owned(bytes) => Cow::Owned(OsString::from_vec(bytes)),
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let lossy = path.to_string_lossy();
+        Cow::Owned(OsString::from(elide_path(&lossy, width, mode).as_ref()))
+    }
+}
+
+/// A function truncating an OS string to a width (in units) according to an alignment mode.
+type TruncateFn = for<'a> fn(&'a OsStr, usize, Alignment) -> Cow<'a, OsStr>;
+
+/// Pads (or truncates using `truncate`) the `s`, which contains `n_units` units, into the
+/// `buffer` and returns the outcome. Content which is truncated to fewer than `width` units,
+/// e.g., elided paths, is padded to `width`.
+fn pad_os_str_to_buffer(
+    s: &OsStr,
+    n_units: usize,
+    width: usize,
+    mode: Alignment,
+    symbol: char,
+    buffer: &mut OsString,
+    truncate: TruncateFn,
+) -> PadOutcome {
+    let n_bytes_buffer: usize = buffer.len();
+    let (content, n_units_kept): (Cow<'_, OsStr>, usize) = if width < n_units {
+        let truncated: Cow<'_, OsStr> = truncate(s, width, mode);
+        let n_units_truncated: usize = self::n_units(&truncated);
+        (truncated, n_units_truncated)
+    } else {
+        (Cow::Borrowed(s), n_units)
+    };
+
+    let mut encoded = [0u8; 4];
+    let symbol_str: &str = symbol.encode_utf8(&mut encoded);
+    let pads = mode.pads(width - n_units_kept);
+    (0..pads.left()).for_each(|_| buffer.push(symbol_str));
+    buffer.push(&content);
+    (0..pads.right()).for_each(|_| buffer.push(symbol_str));
+
+    PadOutcome::with_kept(n_units, n_units_kept, width, mode)
+        .with_bytes(symbol.len_utf8(), buffer.len() - n_bytes_buffer)
+}
+
+/// Implements [`Source`], [`TruncatingSource`] and [`OutcomeSource`] for an OS string type,
+/// delegating to [`pad_os_str_to_buffer`] with the specified truncation function.
+macro_rules! impl_os_str_source {
+    ($ty:ty, $truncate:expr) => {
+        impl Source for $ty {
+            type Symbol = char;
+            type Buffer = OsString;
+            type Output = OsString;
+            type Slice<'a>
+                = Cow<'a, OsStr>
+            where
+                Self: 'a;
+
+            /// Truncates the buffer to match the specified `width` (in units) according to the
+            /// specified alignment `mode`, borrowing from the buffer whenever possible.
+            ///
+            /// Paths elide whole components from the middle instead, keeping the file name.
+            fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
+                let s: &OsStr = self.as_ref();
+                if n_units(s) <= width {
+                    return Cow::Borrowed(s);
+                }
+                $truncate(s, width, mode)
+            }
+
+            /// Pads or truncates the buffer to match the specified `width` (in units) according
+            /// to the specified alignment `mode`.
+            ///
+            /// # Examples
+            /// ```
+            /// use padder::*;
+            /// use std::ffi::OsStr;
+            /// use std::path::Path;
+            ///
+            /// let s: &OsStr = OsStr::new("lordran");
+            /// assert_eq!(OsStr::new("lordran***"), s.pad(10, Alignment::Left, '*'));
+            ///
+            /// let path: &Path = Path::new("/home/u/games/souls/lordran.sav");
+            /// assert_eq!(OsStr::new("/…/souls/lordran.sav"), path.pad(20, Alignment::Right, ' '));
+            /// ```
+            fn pad(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: Self::Symbol,
+            ) -> Self::Output {
+                self.pad_with_outcome(width, mode, symbol).0
+            }
+
+            fn pad_to_buffer(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: Self::Symbol,
+                buffer: &mut Self::Buffer,
+            ) {
+                self.pad_to_buffer_with_outcome(width, mode, symbol, buffer);
+            }
+        }
+
+        impl TruncatingSource for $ty {
+            fn pad_truncating(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: char,
+                truncation: Truncation,
+            ) -> Self::Output {
+                let s: &OsStr = self.as_ref();
+                let mut output = OsString::with_capacity(s.len());
+                self.pad_to_buffer_truncating(width, mode, symbol, truncation, &mut output);
+                output
+            }
+
+            fn pad_to_buffer_truncating(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: char,
+                truncation: Truncation,
+                buffer: &mut Self::Buffer,
+            ) {
+                let s: &OsStr = self.as_ref();
+                let n_units_original: usize = n_units(s);
+                let width: usize = width.into().resolve(n_units_original);
+                let truncate: TruncateFn = match truncation {
+                    Truncation::Cut => truncate_os_str,
+                    Truncation::ElidePath => truncate_path,
+                };
+                pad_os_str_to_buffer(s, n_units_original, width, mode, symbol, buffer, truncate);
+            }
+        }
+
+        impl OutcomeSource for $ty {
+            fn pad_with_outcome(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: Self::Symbol,
+            ) -> (Self::Output, PadOutcome) {
+                let s: &OsStr = self.as_ref();
+                let mut output = OsString::with_capacity(s.len());
+                let outcome = self.pad_to_buffer_with_outcome(width, mode, symbol, &mut output);
+                (output, outcome)
+            }
+
+            fn pad_to_buffer_with_outcome(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: Self::Symbol,
+                buffer: &mut Self::Buffer,
+            ) -> PadOutcome {
+                let s: &OsStr = self.as_ref();
+                let n_units_original: usize = n_units(s);
+                let width: usize = width.into().resolve(n_units_original);
+                pad_os_str_to_buffer(s, n_units_original, width, mode, symbol, buffer, $truncate)
+            }
+        }
+    };
+}
+
+impl_os_str_source!(&OsStr, truncate_os_str);
+impl_os_str_source!(OsString, truncate_os_str);
+impl_os_str_source!(&Path, truncate_path);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::Pads;
+
+    #[test]
+    fn os_str_pad() {
+        let s: &OsStr = OsStr::new("grace");
+        assert_eq!(OsStr::new("grace~~~"), s.pad(8, Alignment::Left, '~'));
+        assert_eq!(OsStr::new("🦀grace🦀🦀"), s.pad(8, Alignment::Center, '🦀'));
+        assert_eq!(OsStr::new("ace"), s.pad(3, Alignment::Right, '~'));
+    }
+
+    #[test]
+    fn os_string_pad_to_buffer() {
+        let s = OsString::from("ツfinger");
+        let mut buffer = OsString::from("| ");
+        s.pad_to_buffer(Width::AtLeast(9), Alignment::Right, '.', &mut buffer);
+        assert_eq!(OsStr::new("| ..ツfinger"), buffer);
+    }
+
+    #[test]
+    fn path_pad() {
+        let path: &Path = Path::new("src/main.rs");
+        assert_eq!(
+            OsStr::new("src/main.rs    "),
+            path.pad(15, Alignment::Left, ' ')
+        );
+    }
+
+    #[test]
+    fn path_elides_components() {
+        let path: &Path = Path::new("/home/u/projects/padder/src/main.rs");
+        assert_eq!(
+            OsStr::new("/…/padder/src/main.rs"),
+            path.pad(21, Alignment::Right, ' ')
+        );
+        assert_eq!(
+            OsStr::new("…/main.rs"),
+            path.truncate_to_fit(9, Alignment::Right)
+        );
+    }
+
+    #[test]
+    fn path_pads_elided() {
+        let path: &Path = Path::new("/home/u/projects/padder/src/main.rs");
+        let (output, outcome) = path.pad_with_outcome(16, Alignment::Right, '.');
+        assert_eq!(OsStr::new("../…/src/main.rs"), output);
+        assert_eq!(2..16, outcome.content);
+        assert_eq!(Pads(2, 0), outcome.pads);
+        assert_eq!(Pads(21, 0), outcome.truncated);
+    }
+
+    #[test]
+    fn pad_truncating_strategies() {
+        let path: &Path = Path::new("/home/u/projects/padder/src/main.rs");
+        assert_eq!(
+            OsStr::new("/home/u/…/src/main.rs"),
+            path.pad_truncating(21, Alignment::Left, ' ', Truncation::ElidePath)
+        );
+        assert_eq!(
+            OsStr::new("/home/u/projects/padd"),
+            path.pad_truncating(21, Alignment::Left, ' ', Truncation::Cut)
+        );
+
+        let s: &OsStr = OsStr::new("/mnt/saves/lordran.sav");
+        assert_eq!(
+            OsStr::new("…/lordran.sav"),
+            s.pad_truncating(13, Alignment::Right, ' ', Truncation::ElidePath)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str_non_utf8() {
+        let s: &OsStr = OsStr::from_bytes(b"ab\xffc\xfe");
+        assert_eq!(5, n_units(s));
+        assert_eq!(
+            OsStr::from_bytes(b"--ab\xffc\xfe"),
+            s.pad(7, Alignment::Right, '-')
+        );
+        assert_eq!(
+            OsStr::from_bytes(b"b\xffc"),
+            s.truncate_to_fit(3, Alignment::Center)
+        );
+        assert_eq!(OsStr::from_bytes(b"c\xfe"), s.pad(2, Alignment::Right, '-'));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_non_utf8() {
+        let path: &Path = Path::new(OsStr::from_bytes(b"/tmp/\xff/file"));
+        assert_eq!(
+            OsStr::new("…/file"),
+            path.truncate_to_fit(6, Alignment::Left)
+        );
+        assert_eq!(
+            OsStr::from_bytes(b"/\xe2\x80\xa6/file"),
+            path.truncate_to_fit(7, Alignment::Left)
+        );
+        assert_eq!(
+            OsStr::from_bytes(b"\xe2\x80\xa6/\xff/file"),
+            path.pad(8, Alignment::Right, '.')
+        );
+    }
+}
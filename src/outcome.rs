@@ -0,0 +1,206 @@
+use std::ops::Range;
+
+use crate::alignment::{Alignment, Pads};
+use crate::mutable_source::MutableSource;
+use crate::source::Source;
+use crate::width::Width;
+
+/// Describes the result of a padding operation, returned by the methods of [`OutcomeSource`]
+/// and [`MutableOutcomeSource`].
+///
+/// - `pads`: the number of padding symbols inserted on each side.
+/// - `truncated`: the number of units (chars for strings, items for vectors and slices)
+///   truncated from each side of the original content.
+/// - `content`: the unit range of the (possibly truncated) content in the output.
+/// - `bytes`: the byte range of the (possibly truncated) content in the output.
+///
+/// A buffer is usually either padded or truncated. Elided paths are the exception: they can
+/// end up narrower than the width and are then padded as well. The elided units are removed
+/// from the middle of the path, but `truncated` still distributes them as per the alignment,
+/// e.g., `Pads(n, 0)` for [`Alignment::Right`].
+///
+/// The ranges are relative to the start of the padded output, i.e., for
+/// `pad_to_buffer_with_outcome` they are relative to the length of the buffer before the call.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let (output, outcome) = "øĸœ".pad_with_outcome(6, Alignment::Center, '🦔');
+/// assert_eq!("🦔øĸœ🦔🦔", output);
+/// assert_eq!(Pads(1, 2), outcome.pads);
+/// assert_eq!(1..4, outcome.content);
+/// assert_eq!("øĸœ", &output[outcome.bytes]);
+///
+/// let (output, outcome) = "elden ring".pad_with_outcome(4, Alignment::Right, ' ');
+/// assert_eq!("ring", output);
+/// assert_eq!(Pads(6, 0), outcome.truncated);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PadOutcome {
+    pub pads: Pads,
+    pub truncated: Pads,
+    pub content: Range<usize>,
+    pub bytes: Range<usize>,
+}
+
+impl PadOutcome {
+    /// Computes the outcome of padding (or truncating) `n_units` to the `width` according to
+    /// the alignment `mode`, with the byte range expressed in units.
+    pub(crate) fn new(n_units: usize, width: usize, mode: Alignment) -> Self {
+        Self::with_kept(n_units, n_units.min(width), width, mode)
+    }
+
+    /// Computes the outcome of truncating `n_units` to `n_units_kept` units and then padding
+    /// them to the `width` according to the alignment `mode`, with the byte range expressed
+    /// in units.
+    pub(crate) fn with_kept(
+        n_units: usize,
+        n_units_kept: usize,
+        width: usize,
+        mode: Alignment,
+    ) -> Self {
+        let pads: Pads = mode.pads(width - n_units_kept);
+        let truncated: Pads = match n_units - n_units_kept {
+            0 => Pads::default(),
+            n_units_truncated => mode.pads(n_units_truncated),
+        };
+
+        let content: Range<usize> = pads.left()..pads.left() + n_units_kept;
+        Self {
+            pads,
+            truncated,
+            bytes: content.clone(),
+            content,
+        }
+    }
+
+    /// Sets the byte range of the content given the size of the padding symbol and the total
+    /// number of bytes in the padded output.
+    pub(crate) fn with_bytes(mut self, symbol_size: usize, n_bytes_output: usize) -> Self {
+        let n_bytes_pads: usize = (self.pads.left() + self.pads.right()) * symbol_size;
+        let st_byte: usize = self.pads.left() * symbol_size;
+        self.bytes = st_byte..st_byte + n_bytes_output - n_bytes_pads;
+        self
+    }
+
+    /// Returns `true` if any padding symbols were inserted.
+    pub fn is_padded(&self) -> bool {
+        self.pads != Pads::default()
+    }
+
+    /// Returns `true` if any of the original content was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated != Pads::default()
+    }
+}
+
+/// An extension of [`Source`] for buffers that can report a [`PadOutcome`] when padded.
+///
+/// The methods live outside of [`Source`] so that implementing it does not require computing
+/// an outcome. Implemented for all the [`Source`] types of this crate.
+pub trait OutcomeSource: Source {
+    /// Pads the buffer like [`Source::pad`], additionally returning a [`PadOutcome`] describing
+    /// the applied padding and where the content ended up in the output.
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome);
+
+    /// Pads into the `buffer` like [`Source::pad_to_buffer`], additionally returning a
+    /// [`PadOutcome`] whose ranges are relative to the length of the `buffer` before the call.
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome;
+}
+
+/// An extension of [`MutableSource`] for buffers that can report a [`PadOutcome`] when padded
+/// in-place.
+///
+/// Implemented for all the [`MutableSource`] types of this crate.
+pub trait MutableOutcomeSource: MutableSource {
+    /// Pads (or truncates) the buffer in-place like [`MutableSource::pad`], additionally
+    /// returning a [`PadOutcome`] describing the applied padding and where the content ended
+    /// up in the buffer.
+    fn pad_with_outcome(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> PadOutcome;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcome_padded() {
+        let outcome = PadOutcome::new(3, 8, Alignment::Center);
+        assert_eq!(Pads(2, 3), outcome.pads);
+        assert_eq!(Pads(0, 0), outcome.truncated);
+        assert_eq!(2..5, outcome.content);
+        assert!(outcome.is_padded() && !outcome.is_truncated());
+    }
+
+    #[test]
+    fn outcome_truncated() {
+        let outcome = PadOutcome::new(9, 4, Alignment::Center);
+        assert_eq!(Pads(0, 0), outcome.pads);
+        assert_eq!(Pads(2, 3), outcome.truncated);
+        assert_eq!(0..4, outcome.content);
+        assert!(!outcome.is_padded() && outcome.is_truncated());
+    }
+
+    #[test]
+    fn outcome_bytes() {
+        let outcome = PadOutcome::new(2, 5, Alignment::Right).with_bytes(4, 12 + 5);
+        assert_eq!(3..5, outcome.content);
+        assert_eq!(12..17, outcome.bytes);
+    }
+
+    #[test]
+    fn outcome_unchanged() {
+        let outcome = PadOutcome::new(4, 4, Alignment::Left);
+        assert_eq!(PadOutcome::new(0, 0, Alignment::Right).pads, outcome.pads);
+        assert_eq!(0..4, outcome.content);
+        assert!(!outcome.is_padded() && !outcome.is_truncated());
+    }
+
+    #[test]
+    fn str_pad_to_buffer_truncated() {
+        let mut buffer = String::from("|");
+        let outcome =
+            "こんにちは".pad_to_buffer_with_outcome(3, Alignment::Center, ' ', &mut buffer);
+        assert_eq!("|んにち", buffer);
+        assert_eq!(Pads(1, 1), outcome.truncated);
+        assert_eq!(0..3, outcome.content);
+        assert_eq!(0..9, outcome.bytes);
+    }
+
+    #[test]
+    fn slice_pad_with_outcome_width() {
+        let source: &[u32] = &[1, 2, 3];
+        let (output, outcome) =
+            source.pad_with_outcome(crate::Width::MultipleOf(4), Alignment::Right, 0);
+        assert_eq!(Vec::from(&[0u32, 1, 2, 3]), output);
+        assert_eq!(Pads(1, 0), outcome.pads);
+        assert_eq!(1..4, outcome.content);
+        assert_eq!(4..16, outcome.bytes);
+    }
+
+    #[test]
+    fn mut_vec_pad_with_outcome() {
+        let mut source: Vec<char> = Vec::from(&['a', 'b', 'c', 'd']);
+        let outcome = (&mut source).pad_with_outcome(1, Alignment::Right, '-');
+        assert_eq!(Vec::from(&['d']), source);
+        assert_eq!(Pads(3, 0), outcome.truncated);
+        assert_eq!(0..4, outcome.bytes);
+    }
+}
@@ -0,0 +1,271 @@
+use std::borrow::Cow;
+use std::path::is_separator;
+
+use crate::alignment::Alignment;
+
+/// The symbol inserted in place of the elided components of a path.
+pub(crate) const ELLIPSIS: char = '…';
+
+/// Returns the number of units in the `bytes`, where a unit is a utf8 char, or an invalid byte
+/// which is counted as a unit of its own.
+fn n_units(bytes: &[u8]) -> usize {
+    bytes
+        .utf8_chunks()
+        .map(|chunk| chunk.valid().chars().count() + chunk.invalid().len())
+        .sum()
+}
+
+/// Returns the byte offset at which the unit with index `idx` of the `bytes` starts, or the
+/// length of the `bytes` if there are not as many units.
+fn unit_offset(bytes: &[u8], idx: usize) -> usize {
+    let mut n_units: usize = 0;
+    let mut byte_offset: usize = 0;
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            if n_units == idx {
+                return byte_offset;
+            }
+            n_units += 1;
+            byte_offset += c.len_utf8();
+        }
+        for _ in chunk.invalid() {
+            if n_units == idx {
+                return byte_offset;
+            }
+            n_units += 1;
+            byte_offset += 1;
+        }
+    }
+    bytes.len()
+}
+
+/// Joins the `head`, the [`ELLIPSIS`], and the `tail`.
+fn join_elided(head: &[u8], tail: &[u8]) -> Vec<u8> {
+    let mut encoded = [0u8; 4];
+    let ellipsis: &[u8] = ELLIPSIS.encode_utf8(&mut encoded).as_bytes();
+    let mut elided: Vec<u8> = Vec::with_capacity(head.len() + ellipsis.len() + tail.len());
+    elided.extend_from_slice(head);
+    elided.extend_from_slice(ellipsis);
+    elided.extend_from_slice(tail);
+    elided
+}
+
+/// Converts the elided `bytes` of a valid UTF-8 string back into a string, which never fails
+/// as they are only ever split at char boundaries.
+fn to_str(bytes: Cow<'_, [u8]>) -> Cow<'_, str> {
+    const VALID: &str = "elided UTF-8 should only be split at char boundaries";
+    match bytes {
+        Cow::Borrowed(bytes) => Cow::Borrowed(std::str::from_utf8(bytes).expect(VALID)),
+        Cow::Owned(bytes) => Cow::Owned(String::from_utf8(bytes).expect(VALID)),
+    }
+}
+
+/// Truncates the path-like `s` to the specified `width` (in utf8 chars) by eliding whole
+/// components from the middle of the path, e.g., `/home/u/…/src/main.rs`.
+///
+/// See [`elide_path_bytes`] for more details.
+pub(crate) fn elide_path(s: &str, width: usize, mode: Alignment) -> Cow<'_, str> {
+    to_str(elide_path_bytes(s.as_bytes(), width, mode))
+}
+
+/// Truncates the path-like (and possibly non-UTF-8) `bytes` to the specified `width` (in
+/// units, where every invalid byte is a unit of its own) by eliding whole components from the
+/// middle of the path, e.g., `/home/u/…/src/main.rs`.
+///
+/// The file name (last component) is always kept. The alignment `mode` decides which other
+/// components are kept greedily as long as they fit:
+/// - [`Alignment::Left`] and [`Alignment::Start`]: leading components first.
+/// - [`Alignment::Right`] and [`Alignment::End`]: trailing components first.
+/// - [`Alignment::Center`]: leading and trailing components alternately.
+///
+/// If there is no room for any other component only the file name is kept, and if the file
+/// name alone does not fit, units are elided from the middle of the file name instead, e.g.,
+/// `ver…me.rs`.
+pub(crate) fn elide_path_bytes(bytes: &[u8], width: usize, mode: Alignment) -> Cow<'_, [u8]> {
+    if n_units(bytes) <= width {
+        return Cow::Borrowed(bytes);
+    }
+
+    let seps: Vec<usize> = bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| b.is_ascii() && is_separator(char::from(**b)))
+        .map(|(byte_offset, _)| byte_offset)
+        .collect();
+    let n_seps: usize = seps.len();
+
+    // `head(k)` keeps the first `k` components (with their trailing separator) and `tail(m)`
+    // keeps the last `m` components (with their leading separator).
+    let head = |k: usize| {
+        if k == 0 {
+            &[][..]
+        } else {
+            &bytes[..seps[k - 1] + 1]
+        }
+    };
+    let tail = |m: usize| &bytes[seps[n_seps - m]..];
+    let fits = |k: usize, m: usize| n_units(head(k)) + 1 + n_units(tail(m)) <= width;
+    let can_grow_head = |k: usize, m: usize| k + m < n_seps && fits(k + 1, m);
+    let can_grow_tail = |k: usize, m: usize| k + m < n_seps && fits(k, m + 1);
+
+    if n_seps == 0 || !fits(0, 1) {
+        let file_name: &[u8] = match seps.last() {
+            Some(byte_offset) => &bytes[byte_offset + 1..],
+            None => bytes,
+        };
+        return elide_units(file_name, width);
+    }
+
+    let (mut k, mut m) = (0, 1);
+    match mode {
+        Alignment::Left | Alignment::Start => {
+            while can_grow_head(k, m) {
+                k += 1;
+            }
+            while can_grow_tail(k, m) {
+                m += 1;
+            }
+        }
+        Alignment::Right | Alignment::End => {
+            while can_grow_tail(k, m) {
+                m += 1;
+            }
+            while can_grow_head(k, m) {
+                k += 1;
+            }
+        }
+        Alignment::Center => loop {
+            let mut grew: bool = false;
+            if can_grow_tail(k, m) {
+                m += 1;
+                grew = true;
+            }
+            if can_grow_head(k, m) {
+                k += 1;
+                grew = true;
+            }
+            if !grew {
+                break;
+            }
+        },
+    }
+
+    Cow::Owned(join_elided(head(k), tail(m)))
+}
+
+/// Truncates the `s` to the specified `width` (in utf8 chars) by eliding chars from the middle
+/// of it, keeping one more char from the end than from the start if the split is uneven.
+#[cfg(test)]
+fn elide_chars(s: &str, width: usize) -> Cow<'_, str> {
+    to_str(elide_units(s.as_bytes(), width))
+}
+
+/// Truncates the `bytes` to the specified `width` (in units) by eliding units from the middle
+/// of them, keeping one more unit from the end than from the start if the split is uneven.
+fn elide_units(bytes: &[u8], width: usize) -> Cow<'_, [u8]> {
+    let n_units: usize = n_units(bytes);
+    if n_units <= width {
+        return Cow::Borrowed(bytes);
+    }
+    if width == 0 {
+        return Cow::Borrowed(&[]);
+    }
+
+    let n_units_head: usize = (width - 1) / 2;
+    let n_units_tail: usize = width - 1 - n_units_head;
+    let head: &[u8] = &bytes[..unit_offset(bytes, n_units_head)];
+    let tail: &[u8] = &bytes[unit_offset(bytes, n_units - n_units_tail)..];
+    Cow::Owned(join_elided(head, tail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elide_path_fits() {
+        assert_eq!(
+            "src/main.rs",
+            elide_path("src/main.rs", 11, Alignment::Center)
+        );
+    }
+
+    #[test]
+    fn elide_path_middle() {
+        let path: &str = "/home/u/projects/padder/src/main.rs";
+        assert_eq!(
+            "/…/padder/src/main.rs",
+            elide_path(path, 21, Alignment::Center)
+        );
+        assert_eq!(
+            "/home/…/padder/src/main.rs",
+            elide_path(path, 26, Alignment::Center)
+        );
+        assert_eq!("…/src/main.rs", elide_path(path, 13, Alignment::Center));
+        assert_eq!("…/main.rs", elide_path(path, 9, Alignment::Center));
+    }
+
+    #[test]
+    fn elide_path_alignment() {
+        let path: &str = "/home/u/projects/padder/src/main.rs";
+        assert_eq!(
+            "/home/u/…/src/main.rs",
+            elide_path(path, 21, Alignment::Left)
+        );
+        assert_eq!(
+            "/home/u/…/src/main.rs",
+            elide_path(path, 21, Alignment::Start)
+        );
+        assert_eq!(
+            "/…/padder/src/main.rs",
+            elide_path(path, 21, Alignment::Right)
+        );
+        assert_eq!(
+            "/…/padder/src/main.rs",
+            elide_path(path, 21, Alignment::End)
+        );
+        assert_eq!("/home/…/main.rs", elide_path(path, 16, Alignment::Left));
+        assert_eq!("/…/src/main.rs", elide_path(path, 16, Alignment::Right));
+    }
+
+    #[test]
+    fn elide_path_file_name_only() {
+        let path: &str = "/home/u/projects/padder/src/main.rs";
+        assert_eq!("main.rs", elide_path(path, 8, Alignment::Center));
+        assert_eq!("main.rs", elide_path(path, 7, Alignment::Center));
+    }
+
+    #[test]
+    fn elide_path_file_name_too_long() {
+        let path: &str = "/home/u/projects/padder/src/main.rs";
+        assert_eq!("ma….rs", elide_path(path, 6, Alignment::Center));
+        assert_eq!("ma…rs", elide_path(path, 5, Alignment::Center));
+        assert_eq!("…", elide_path(path, 1, Alignment::Center));
+        assert_eq!("", elide_path(path, 0, Alignment::Center));
+    }
+
+    #[test]
+    fn elide_path_no_separators() {
+        assert_eq!("m…s", elide_path("main.rs", 3, Alignment::Center));
+    }
+
+    #[test]
+    fn elide_chars_multibyte() {
+        assert_eq!("こ…ちは", elide_chars("こんにちは", 4));
+        assert_eq!("こんにちは", elide_chars("こんにちは", 5));
+    }
+
+    #[test]
+    fn elide_path_bytes_non_utf8() {
+        let path: &[u8] = b"/tmp/\xff\xfe/saves/file";
+        assert_eq!(
+            &b"\xe2\x80\xa6/file"[..],
+            &*elide_path_bytes(path, 6, Alignment::Left)
+        );
+        assert_eq!(
+            &b"/tmp/\xff\xfe/\xe2\x80\xa6/file"[..],
+            &*elide_path_bytes(path, 14, Alignment::Left)
+        );
+        assert_eq!(&b"\xff\xe2\x80\xa6de"[..], &*elide_units(b"\xffabcde", 4));
+    }
+}
@@ -0,0 +1,1087 @@
+use std::fmt;
+use std::io::{BufRead, Write};
+
+#[cfg(feature = "serde")]
+mod de;
+#[cfg(feature = "serde")]
+mod ser;
+
+use crate::alignment::Alignment;
+use crate::source::Source;
+use crate::unpad::Unpad;
+
+/// Specifies what should happen when a value does not fit inside of its field.
+///
+/// - `Truncate`: the value is truncated according to the alignment mode of the field.
+/// - `Error`: the value is rejected with a [`RecordError::Overflow`] error.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Overflow {
+    #[default]
+    Truncate,
+    Error,
+}
+
+/// Describes a single fixed-width field of a record: its name, width (in utf8 chars), alignment
+/// `mode`, padding `symbol`, and [`Overflow`] policy.
+///
+/// With the `serde` feature enabled a field can be (de)serialized, e.g., from a config file,
+/// where only `name` and `width` are required:
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use padder::*;
+///
+/// let json = r#"{ "name": "amount", "width": 10, "mode": ">", "symbol": "0" }"#;
+/// let field: RecordField = serde_json::from_str(json).unwrap();
+/// assert_eq!(RecordField::new("amount", 10, Alignment::Right, '0'), field);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordField {
+    pub name: String,
+    pub width: usize,
+    #[cfg_attr(feature = "serde", serde(default, alias = "align"))]
+    pub mode: Alignment,
+    #[cfg_attr(feature = "serde", serde(default = "default_symbol"))]
+    pub symbol: char,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub overflow: Overflow,
+}
+
+#[cfg(feature = "serde")]
+fn default_symbol() -> char {
+    ' '
+}
+
+impl RecordField {
+    /// Creates a new field which truncates values that are wider than `width`.
+    pub fn new(name: impl Into<String>, width: usize, mode: Alignment, symbol: char) -> Self {
+        Self {
+            name: name.into(),
+            width,
+            mode,
+            symbol,
+            overflow: Overflow::default(),
+        }
+    }
+
+    /// Returns the field with its [`Overflow`] policy replaced.
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Pads (or truncates) the `value` into the `buffer` according to the field definition.
+    fn write(&self, value: &str, buffer: &mut String) -> Result<(), RecordError> {
+        if self.overflow == Overflow::Error {
+            let n_chars: usize = value.chars().count();
+            if n_chars > self.width {
+                return Err(RecordError::Overflow {
+                    field: self.name.clone(),
+                    width: self.width,
+                    found: n_chars,
+                });
+            }
+        }
+
+        value.pad_to_buffer(self.width, self.mode, self.symbol, buffer);
+        Ok(())
+    }
+
+    /// Strips the padding symbols from the `value` according to the alignment mode of the field.
+    ///
+    /// A value which consists of nothing but padding reads back as an empty value, except in
+    /// right-aligned `'0'`-filled (numeric) fields, where it keeps a single `'0'` such that a
+    /// zero reads back as `"0"`.
+    fn strip<'a>(&self, value: &'a str) -> &'a str {
+        let stripped: &str = value.unpad(self.mode, self.symbol);
+        let is_numeric: bool =
+            self.symbol == '0' && matches!(self.mode, Alignment::Right | Alignment::End);
+        if stripped.is_empty() && !value.is_empty() && is_numeric {
+            return &value[value.len() - self.symbol.len_utf8()..];
+        }
+        stripped
+    }
+}
+
+/// An ordered list of [`RecordField`]s describing the layout of a fixed-width line, e.g.,
+/// a COBOL copybook export or a bank ACH/NACHA file.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let schema = RecordSchema::new(vec![
+///     RecordField::new("name", 8, Alignment::Left, ' '),
+///     RecordField::new("level", 4, Alignment::Right, '0'),
+///     RecordField::new("class", 9, Alignment::Center, '*'),
+/// ]).unwrap();
+///
+/// let line = schema.to_line(&["hunter", "42", "bloodborne"]).unwrap();
+/// assert_eq!("hunter  0042bloodborn", line);
+///
+/// let fields = schema.read_record(&line).unwrap();
+/// assert_eq!(vec!["hunter", "42", "bloodborn"], fields);
+/// ```
+///
+/// With the `serde` feature enabled a schema is (de)serialized as a list of its fields.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct RecordSchema {
+    fields: Vec<RecordField>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RecordSchema {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields: Vec<RecordField> = Vec::deserialize(deserializer)?;
+        Self::new(fields).map_err(serde::de::Error::custom)
+    }
+}
+
+impl RecordSchema {
+    /// Creates a new schema from the `fields`, returning a [`RecordError::ZeroWidth`] error if
+    /// any of the fields is zero chars wide.
+    pub fn new(fields: Vec<RecordField>) -> Result<Self, RecordError> {
+        if let Some(field) = fields.iter().find(|field| field.width == 0) {
+            return Err(RecordError::ZeroWidth {
+                field: field.name.clone(),
+            });
+        }
+        Ok(Self { fields })
+    }
+
+    /// Get the field definitions of the schema.
+    pub fn fields(&self) -> &[RecordField] {
+        &self.fields
+    }
+
+    /// Get the total width (in utf8 chars) of a line described by the schema.
+    pub fn width(&self) -> usize {
+        self.fields.iter().map(|field| field.width).sum()
+    }
+
+    /// Serializes the `values` into a line by padding each value according to its field
+    /// definition and writing it into the provided `buffer`.
+    ///
+    /// The `buffer` is left untouched if any of the values could not be written.
+    pub fn write_record<S: AsRef<str>>(
+        &self,
+        values: &[S],
+        buffer: &mut String,
+    ) -> Result<(), RecordError> {
+        if values.len() != self.fields.len() {
+            return Err(RecordError::FieldCount {
+                expected: self.fields.len(),
+                found: values.len(),
+            });
+        }
+
+        let n_bytes_original: usize = buffer.len();
+        for (field, value) in self.fields.iter().zip(values) {
+            if let Err(err) = field.write(value.as_ref(), buffer) {
+                buffer.truncate(n_bytes_original);
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the `values` into a newly allocated line.
+    ///
+    /// See [`RecordSchema::write_record`] for more details.
+    pub fn to_line<S: AsRef<str>>(&self, values: &[S]) -> Result<String, RecordError> {
+        let mut line = String::with_capacity(self.width());
+        self.write_record(values, &mut line)?;
+        Ok(line)
+    }
+
+    /// Serializes the scalar fields of the `value` into a line by padding each of them
+    /// according to its field definition and writing it into the provided `buffer`.
+    ///
+    /// Structs are serialized field by field where the name of each field has to match the
+    /// name of the next field in the schema, tuples and sequences are serialized by position.
+    /// `None` and unit values are written as an empty (fully padded) field.
+    ///
+    /// The `buffer` is left untouched if the value could not be serialized.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "serde")]
+    /// # {
+    /// use padder::*;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Payment<'a> {
+    ///     id: u32,
+    ///     payee: &'a str,
+    ///     amount: f64,
+    /// }
+    ///
+    /// let schema = RecordSchema::new(vec![
+    ///     RecordField::new("id", 4, Alignment::Right, '0'),
+    ///     RecordField::new("payee", 8, Alignment::Left, ' '),
+    ///     RecordField::new("amount", 6, Alignment::Right, ' '),
+    /// ]).unwrap();
+    ///
+    /// let mut line = String::new();
+    /// let payment = Payment { id: 7, payee: "andre", amount: 12.5 };
+    /// schema.serialize_to_buffer(&payment, &mut line).unwrap();
+    /// assert_eq!("0007andre     12.5", line);
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn serialize_to_buffer<T>(&self, value: &T, buffer: &mut String) -> Result<(), RecordError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        let n_bytes_original: usize = buffer.len();
+        let result = value.serialize(&mut ser::LineSerializer::new(self, buffer));
+        if result.is_err() {
+            buffer.truncate(n_bytes_original);
+        }
+        result
+    }
+
+    /// Serializes the scalar fields of the `value` into a newly allocated line.
+    ///
+    /// See [`RecordSchema::serialize_to_buffer`] for more details.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn serialize<T>(&self, value: &T) -> Result<String, RecordError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        let mut line = String::with_capacity(self.width());
+        self.serialize_to_buffer(value, &mut line)?;
+        Ok(line)
+    }
+
+    /// Deserializes a value from the `line` by slicing it into its fields and stripping the
+    /// padding from each of them, see [`RecordSchema::read_record`].
+    ///
+    /// Structs are deserialized by the names of the fields in the schema (fields in the schema
+    /// which are not part of the struct are ignored), tuples and sequences by position.
+    /// Empty (whitespace-filled) fields are deserialized as `None` for optional values.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "serde")]
+    /// # {
+    /// use padder::*;
+    ///
+    /// #[derive(serde::Deserialize, Debug, PartialEq)]
+    /// struct Payment {
+    ///     id: u32,
+    ///     payee: String,
+    ///     amount: f64,
+    /// }
+    ///
+    /// let schema = RecordSchema::new(vec![
+    ///     RecordField::new("id", 4, Alignment::Right, '0'),
+    ///     RecordField::new("payee", 8, Alignment::Left, ' '),
+    ///     RecordField::new("amount", 6, Alignment::Right, ' '),
+    /// ]).unwrap();
+    ///
+    /// let payment: Payment = schema.deserialize("0007andre     12.5").unwrap();
+    /// assert_eq!(Payment { id: 7, payee: String::from("andre"), amount: 12.5 }, payment);
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn deserialize<'a, T>(&self, line: &'a str) -> Result<T, RecordError>
+    where
+        T: serde::Deserialize<'a>,
+    {
+        let values: Vec<&'a str> = self.read_record(line)?;
+        T::deserialize(&mut de::LineDeserializer::new(&self.fields, values))
+    }
+
+    /// Slices the `line` into its fields and strips the padding from each of them.
+    ///
+    /// Fields which consist of nothing but padding read back as empty values, except in
+    /// right-aligned `'0'`-filled fields, which keep a single `'0'` such that zeros round-trip.
+    ///
+    /// The line has to be exactly as wide as the schema, otherwise a
+    /// [`RecordError::LineTooShort`] or [`RecordError::LineTooLong`] error is returned.
+    pub fn read_record<'a>(&self, line: &'a str) -> Result<Vec<&'a str>, RecordError> {
+        let mut values: Vec<&'a str> = Vec::with_capacity(self.fields.len());
+        let mut chars = line.char_indices();
+        let mut st_byte: usize = 0;
+        let mut n_chars: usize = 0;
+
+        for field in &self.fields {
+            let mut ed_byte: usize = st_byte;
+            for _ in 0..field.width {
+                match chars.next() {
+                    Some((byte_offset, c)) => ed_byte = byte_offset + c.len_utf8(),
+                    None => {
+                        return Err(RecordError::LineTooShort {
+                            expected: self.width(),
+                            found: n_chars,
+                        });
+                    }
+                }
+                n_chars += 1;
+            }
+
+            values.push(field.strip(&line[st_byte..ed_byte]));
+            st_byte = ed_byte;
+        }
+
+        let n_chars_remaining: usize = chars.count();
+        if n_chars_remaining > 0 {
+            return Err(RecordError::LineTooLong {
+                expected: self.width(),
+                found: n_chars + n_chars_remaining,
+            });
+        }
+
+        Ok(values)
+    }
+}
+
+/// A type which can render itself into a fixed-width line, one padded field at a time.
+///
+/// Instead of implementing this trait by hand it can be derived for any struct with the
+/// `derive` feature enabled, where each field is rendered through its [`std::fmt::Display`]
+/// implementation and padded according to its `#[pad(...)]` attribute:
+/// - `width = <usize>`: the width (in utf8 chars) of the field, at least 1 (required).
+/// - `align = "left" | "right" | "center" | "start" | "end"`: the alignment mode (defaults to
+///   [`Alignment::default()`]).
+/// - `symbol = <char>`: the padding symbol (defaults to `' '`).
+/// - `skip`: excludes the field from the rendered line.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use padder::*;
+///
+/// #[derive(PadRecord)]
+/// struct Boss {
+///     #[pad(width = 8, align = "left")]
+///     name: &'static str,
+///     #[pad(width = 5, align = "right", symbol = '0')]
+///     souls: u32,
+///     #[pad(skip)]
+///     defeated: bool,
+/// }
+///
+/// let boss = Boss { name: "gwyn", souls: 7000, defeated: true };
+/// assert_eq!(13, Boss::WIDTH);
+/// assert_eq!("gwyn    07000", boss.pad_record());
+/// # }
+/// ```
+pub trait PadRecord {
+    /// The width (in utf8 chars) of a rendered line.
+    const WIDTH: usize;
+
+    /// Renders the record into a fixed-width line by writing into the provided `buffer`.
+    fn pad_record_to_buffer(&self, buffer: &mut String);
+
+    /// Renders the record into a newly allocated fixed-width line.
+    fn pad_record(&self) -> String {
+        let mut buffer = String::with_capacity(Self::WIDTH);
+        self.pad_record_to_buffer(&mut buffer);
+        buffer
+    }
+}
+
+/// Writes records as newline terminated fixed-width lines into the wrapped writer.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let schema = RecordSchema::new(vec![
+///     RecordField::new("id", 3, Alignment::Right, '0'),
+///     RecordField::new("boss", 7, Alignment::Left, '.'),
+/// ]).unwrap();
+///
+/// let mut writer = RecordWriter::new(&schema, Vec::new());
+/// writer.write_record(&["1", "gael"]).unwrap();
+/// writer.write_record(&["2", "midir"]).unwrap();
+///
+/// let output = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!("001gael...\n002midir..\n", output);
+/// ```
+#[derive(Debug)]
+pub struct RecordWriter<'s, W> {
+    schema: &'s RecordSchema,
+    inner: W,
+    line: String,
+}
+
+impl<'s, W: Write> RecordWriter<'s, W> {
+    pub fn new(schema: &'s RecordSchema, inner: W) -> Self {
+        Self {
+            schema,
+            inner,
+            line: String::with_capacity(schema.width() + 1),
+        }
+    }
+
+    /// Serializes the `values` into a line and writes it, followed by a newline, to the
+    /// wrapped writer.
+    pub fn write_record<S: AsRef<str>>(&mut self, values: &[S]) -> Result<(), RecordError> {
+        self.line.clear();
+        self.schema.write_record(values, &mut self.line)?;
+        self.line.push('\n');
+        self.inner.write_all(self.line.as_bytes())?;
+        Ok(())
+    }
+
+    /// Serializes the scalar fields of the `value` into a line and writes it, followed by a
+    /// newline, to the wrapped writer.
+    ///
+    /// See [`RecordSchema::serialize_to_buffer`] for more details.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn serialize<T>(&mut self, value: &T) -> Result<(), RecordError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.line.clear();
+        self.schema.serialize_to_buffer(value, &mut self.line)?;
+        self.line.push('\n');
+        self.inner.write_all(self.line.as_bytes())?;
+        Ok(())
+    }
+
+    /// Unwraps the writer, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads newline terminated fixed-width lines from the wrapped reader and yields the
+/// stripped fields of each record.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let schema = RecordSchema::new(vec![
+///     RecordField::new("id", 3, Alignment::Right, '0'),
+///     RecordField::new("boss", 7, Alignment::Left, '.'),
+/// ]).unwrap();
+///
+/// let input: &[u8] = b"001gael...\n002midir..\n";
+/// let records: Vec<Vec<String>> = RecordReader::new(&schema, input)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(vec!["1", "gael"], records[0]);
+/// assert_eq!(vec!["2", "midir"], records[1]);
+/// ```
+#[derive(Debug)]
+pub struct RecordReader<'s, R> {
+    schema: &'s RecordSchema,
+    inner: R,
+    line: String,
+}
+
+impl<'s, R: BufRead> RecordReader<'s, R> {
+    pub fn new(schema: &'s RecordSchema, inner: R) -> Self {
+        Self {
+            schema,
+            inner,
+            line: String::with_capacity(schema.width() + 1),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for RecordReader<'_, R> {
+    type Item = Result<Vec<String>, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
+        match self.inner.read_line(&mut self.line) {
+            Ok(0) => None,
+            Ok(_) => {
+                let line = self.line.trim_end_matches(['\n', '\r']);
+                let record = self
+                    .schema
+                    .read_record(line)
+                    .map(|values| values.into_iter().map(String::from).collect());
+                Some(record)
+            }
+            Err(err) => Some(Err(err.into())),
+        }
+    }
+}
+
+/// The errors that can occur when writing or reading fixed-width records.
+#[derive(Debug)]
+pub enum RecordError {
+    /// The number of values did not match the number of fields in the schema.
+    FieldCount { expected: usize, found: usize },
+    /// A value was wider than its field which has the [`Overflow::Error`] policy.
+    Overflow {
+        field: String,
+        width: usize,
+        found: usize,
+    },
+    /// A field of the schema was zero chars wide.
+    ZeroWidth { field: String },
+    /// The line contained fewer chars than described by the schema.
+    LineTooShort { expected: usize, found: usize },
+    /// The line contained more chars than described by the schema.
+    LineTooLong { expected: usize, found: usize },
+    /// A serialized struct field did not match the next field of the schema.
+    UnexpectedField { expected: String, found: String },
+    /// A custom error, e.g., a value that could not be (de)serialized.
+    Message(String),
+    /// The underlying writer or reader failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldCount { expected, found } => {
+                write!(f, "expected {expected} values but found {found}")
+            }
+            Self::Overflow {
+                field,
+                width,
+                found,
+            } => write!(
+                f,
+                "value of field '{field}' is {found} chars wide but the field is only {width} chars wide"
+            ),
+            Self::ZeroWidth { field } => {
+                write!(f, "field '{field}' has to be at least 1 char wide")
+            }
+            Self::LineTooShort { expected, found } => {
+                write!(
+                    f,
+                    "line is too short, expected {expected} chars but found {found}"
+                )
+            }
+            Self::LineTooLong { expected, found } => {
+                write!(
+                    f,
+                    "line is too long, expected {expected} chars but found {found}"
+                )
+            }
+            Self::UnexpectedField { expected, found } => {
+                write!(f, "expected field '{expected}' but found '{found}'")
+            }
+            Self::Message(msg) => f.write_str(msg),
+            Self::Io(err) => write!(f, "io error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RecordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for RecordError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests_derive {
+    use super::*;
+    use crate::PadRecord;
+
+    #[derive(PadRecord)]
+    struct Transaction<'a> {
+        #[pad(width = 6, align = "right", symbol = '0')]
+        id: u32,
+        #[pad(width = 10, align = "left")]
+        payee: &'a str,
+        #[pad(skip)]
+        #[allow(dead_code)]
+        note: String,
+        #[pad(width = 9, align = "center", symbol = '*')]
+        amount: f64,
+    }
+
+    #[derive(PadRecord)]
+    struct Skipped {
+        #[pad(skip)]
+        #[allow(dead_code)]
+        note: String,
+    }
+
+    #[derive(PadRecord)]
+    struct Code(#[pad(width = 4, symbol = '_')] char, #[pad(width = 2)] u8);
+
+    #[test]
+    fn derive_named() {
+        let transaction = Transaction {
+            id: 42,
+            payee: "blacksmith andre",
+            note: String::from("ignored"),
+            amount: 12.5,
+        };
+        assert_eq!(25, Transaction::WIDTH);
+        assert_eq!("000042blacksmith**12.5***", transaction.pad_record());
+    }
+
+    #[test]
+    fn derive_unnamed() {
+        let mut buffer = String::from("code:");
+        Code('ツ', 7).pad_record_to_buffer(&mut buffer);
+        assert_eq!("code:___ツ 7", buffer);
+        assert_eq!(6, Code::WIDTH);
+    }
+
+    #[test]
+    fn derive_all_skipped() {
+        let skipped = Skipped {
+            note: String::from("praise the sun"),
+        };
+        assert_eq!("", skipped.pad_record());
+        assert_eq!(0, Skipped::WIDTH);
+    }
+
+    #[test]
+    fn derive_matches_schema() {
+        let schema = RecordSchema::new(vec![
+            RecordField::new("id", 6, Alignment::Right, '0'),
+            RecordField::new("payee", 10, Alignment::Left, ' '),
+            RecordField::new("amount", 9, Alignment::Center, '*'),
+        ])
+        .unwrap();
+        let transaction = Transaction {
+            id: 7,
+            payee: "patches",
+            note: String::new(),
+            amount: 0.25,
+        };
+        let line = transaction.pad_record();
+        assert_eq!(
+            vec!["7", "patches", "0.25"],
+            schema.read_record(&line).unwrap()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_serde {
+    use super::*;
+
+    #[test]
+    fn schema_deserialize() {
+        let json = r#"[
+            { "name": "name", "width": 10, "align": "left" },
+            { "name": "amount", "width": 8, "mode": "right", "symbol": "0", "overflow": "error" },
+            { "name": "tag", "width": 6, "mode": "^", "symbol": "*" }
+        ]"#;
+        let schema: RecordSchema = serde_json::from_str(json).unwrap();
+        let expected = RecordSchema::new(vec![
+            RecordField::new("name", 10, Alignment::Left, ' '),
+            RecordField::new("amount", 8, Alignment::Right, '0').with_overflow(Overflow::Error),
+            RecordField::new("tag", 6, Alignment::Center, '*'),
+        ])
+        .unwrap();
+        assert_eq!(expected, schema);
+    }
+
+    #[test]
+    fn schema_roundtrip() {
+        let schema = RecordSchema::new(vec![
+            RecordField::new("id", 3, Alignment::Right, '0'),
+            RecordField::new("boss", 7, Alignment::Left, '.').with_overflow(Overflow::Error),
+        ])
+        .unwrap();
+        let json = serde_json::to_string(&schema).unwrap();
+        assert_eq!(
+            r#"[{"name":"id","width":3,"mode":"right","symbol":"0","overflow":"truncate"},{"name":"boss","width":7,"mode":"left","symbol":".","overflow":"error"}]"#,
+            json
+        );
+        assert_eq!(schema, serde_json::from_str(&json).unwrap());
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Covenant {
+        Sunlight,
+        Darkwraith,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Player {
+        name: String,
+        level: u16,
+        covenant: Covenant,
+        rank: Option<u8>,
+    }
+
+    fn player_schema() -> RecordSchema {
+        RecordSchema::new(vec![
+            RecordField::new("name", 8, Alignment::Left, ' '),
+            RecordField::new("level", 3, Alignment::Right, '0'),
+            RecordField::new("covenant", 10, Alignment::Center, '*'),
+            RecordField::new("rank", 2, Alignment::Right, ' '),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn serialize_struct() {
+        let player = Player {
+            name: String::from("solaire"),
+            level: 42,
+            covenant: Covenant::Sunlight,
+            rank: Some(3),
+        };
+        let line = player_schema().serialize(&player).unwrap();
+        assert_eq!("solaire 042*sunlight* 3", line);
+    }
+
+    #[test]
+    fn serialize_none_and_truncate() {
+        let player = Player {
+            name: String::from("lautrec of carim"),
+            level: 7,
+            covenant: Covenant::Darkwraith,
+            rank: None,
+        };
+        let line = player_schema().serialize(&player).unwrap();
+        assert_eq!("lautrec 007darkwraith  ", line);
+    }
+
+    #[test]
+    fn serialize_tuple() {
+        let line = player_schema().serialize(&("ツ", 1u8, 'x', false)).unwrap();
+        assert_eq!("ツ       001****x*****se", line);
+    }
+
+    #[test]
+    fn serialize_unexpected_field() {
+        #[derive(serde::Serialize)]
+        struct Wrong {
+            level: u16,
+        }
+        let mut buffer = String::from("header|");
+        let err = player_schema()
+            .serialize_to_buffer(&Wrong { level: 1 }, &mut buffer)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::UnexpectedField { ref expected, ref found }
+                if expected == "name" && found == "level"
+        ));
+        assert_eq!("header|", buffer);
+    }
+
+    #[test]
+    fn serialize_field_count() {
+        let err = player_schema().serialize(&("a", 1, 2)).unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::FieldCount {
+                expected: 4,
+                found: 3
+            }
+        ));
+        let err = player_schema().serialize(&[1, 2, 3, 4, 5]).unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::FieldCount {
+                expected: 4,
+                found: 5
+            }
+        ));
+    }
+
+    #[test]
+    fn serialize_unsupported() {
+        let err = player_schema().serialize(&42u32).unwrap_err();
+        assert!(matches!(err, RecordError::Message(_)));
+        let err = player_schema()
+            .serialize(&("a", vec![1, 2], 'c', 'd'))
+            .unwrap_err();
+        assert!(err.to_string().contains("field 'level'"));
+    }
+
+    #[test]
+    fn serialize_overflow_error() {
+        let schema = RecordSchema::new(vec![
+            RecordField::new("id", 2, Alignment::Right, '0').with_overflow(Overflow::Error),
+        ])
+        .unwrap();
+        let err = schema.serialize(&(12345,)).unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::Overflow {
+                width: 2,
+                found: 5,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn deserialize_struct() {
+        let player: Player = player_schema()
+            .deserialize("solaire 042*sunlight* 3")
+            .unwrap();
+        let expected = Player {
+            name: String::from("solaire"),
+            level: 42,
+            covenant: Covenant::Sunlight,
+            rank: Some(3),
+        };
+        assert_eq!(expected, player);
+    }
+
+    #[test]
+    fn deserialize_empty_option() {
+        let player: Player = player_schema()
+            .deserialize("lautrec 007darkwraith  ")
+            .unwrap();
+        assert_eq!(None, player.rank);
+        assert_eq!(Covenant::Darkwraith, player.covenant);
+    }
+
+    #[test]
+    fn deserialize_borrowed_tuple() {
+        let (name, level, covenant, rank): (&str, u32, &str, Option<i8>) = player_schema()
+            .deserialize("ツ       001****x*****-1")
+            .unwrap();
+        assert_eq!(("ツ", 1, "x", Some(-1)), (name, level, covenant, rank));
+    }
+
+    #[test]
+    fn deserialize_ignores_unknown_fields() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Partial {
+            level: u16,
+        }
+        let partial: Partial = player_schema()
+            .deserialize("solaire 042*sunlight* 3")
+            .unwrap();
+        assert_eq!(Partial { level: 42 }, partial);
+    }
+
+    #[test]
+    fn deserialize_invalid_value() {
+        let err = player_schema()
+            .deserialize::<Player>("solaire 1x2*sunlight* 3")
+            .unwrap_err();
+        assert_eq!(
+            "invalid value '1x2' for field 'level', expected u16",
+            err.to_string()
+        );
+        let err = player_schema()
+            .deserialize::<Player>("solaire 042")
+            .unwrap_err();
+        assert!(matches!(err, RecordError::LineTooShort { .. }));
+    }
+
+    #[test]
+    fn deserialize_zero() {
+        let schema = RecordSchema::new(vec![
+            RecordField::new("souls", 4, Alignment::Right, '0'),
+            RecordField::new("rank", 2, Alignment::Right, ' '),
+        ])
+        .unwrap();
+        let line = schema.serialize(&(0u32, None::<u8>)).unwrap();
+        assert_eq!("0000  ", line);
+        assert_eq!(
+            (0, None),
+            schema.deserialize::<(u32, Option<u8>)>(&line).unwrap()
+        );
+    }
+
+    #[test]
+    fn schema_deserialize_zero_width() {
+        let json = r#"[{ "name": "id", "width": 0 }]"#;
+        let err = serde_json::from_str::<RecordSchema>(json).unwrap_err();
+        assert_eq!("field 'id' has to be at least 1 char wide", err.to_string());
+    }
+
+    #[test]
+    fn writer_serialize() {
+        let schema = player_schema();
+        let mut writer = RecordWriter::new(&schema, Vec::new());
+        writer.serialize(&("a", 1, "b", 2)).unwrap();
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!("a       001****b***** 2\n", output);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> RecordSchema {
+        RecordSchema::new(vec![
+            RecordField::new("name", 10, Alignment::Left, ' '),
+            RecordField::new("amount", 8, Alignment::Right, '0'),
+            RecordField::new("tag", 6, Alignment::Center, '*'),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn schema_width() {
+        assert_eq!(24, schema().width());
+        assert_eq!(0, RecordSchema::default().width());
+    }
+
+    #[test]
+    fn write_record() {
+        let mut buffer = String::new();
+        schema()
+            .write_record(&["malenia", "1337", "rot"], &mut buffer)
+            .unwrap();
+        assert_eq!("malenia   00001337*rot**", buffer);
+    }
+
+    #[test]
+    fn write_record_truncates() {
+        let line = schema()
+            .to_line(&["radagon of the golden order", "123456789", "elden"])
+            .unwrap();
+        assert_eq!("radagon of23456789elden*", line);
+        assert_eq!(24, line.chars().count());
+    }
+
+    #[test]
+    fn write_record_multibyte() {
+        let line = schema().to_line(&["こんにちは", "7", "🐉"]).unwrap();
+        assert_eq!("こんにちは     00000007**🐉***", line);
+        assert_eq!(24, line.chars().count());
+    }
+
+    #[test]
+    fn schema_zero_width() {
+        let err = RecordSchema::new(vec![
+            RecordField::new("id", 2, Alignment::Right, '0'),
+            RecordField::new("flags", 0, Alignment::Right, ' '),
+        ])
+        .unwrap_err();
+        assert!(matches!(err, RecordError::ZeroWidth { ref field } if field == "flags"));
+    }
+
+    #[test]
+    fn write_record_overflow_error() {
+        let schema = RecordSchema::new(vec![
+            RecordField::new("id", 2, Alignment::Right, '0'),
+            RecordField::new("name", 4, Alignment::Left, ' ').with_overflow(Overflow::Error),
+        ])
+        .unwrap();
+        let mut buffer = String::from("header|");
+        let err = schema
+            .write_record(&["1", "godrick"], &mut buffer)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::Overflow { ref field, width: 4, found: 7 } if field == "name"
+        ));
+        assert_eq!("header|", buffer);
+    }
+
+    #[test]
+    fn write_record_field_count() {
+        let err = schema().to_line(&["rennala"]).unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::FieldCount {
+                expected: 3,
+                found: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn read_record() {
+        let values = schema().read_record("malenia   00001337*rot**").unwrap();
+        assert_eq!(vec!["malenia", "1337", "rot"], values);
+    }
+
+    #[test]
+    fn read_record_zero() {
+        let line = schema().to_line(&["", "0", ""]).unwrap();
+        assert_eq!("          00000000******", line);
+        assert_eq!(vec!["", "0", ""], schema().read_record(&line).unwrap());
+    }
+
+    #[test]
+    fn read_record_multibyte() {
+        let values = schema()
+            .read_record("こんにちは     00000007**🐉***")
+            .unwrap();
+        assert_eq!(vec!["こんにちは", "7", "🐉"], values);
+    }
+
+    #[test]
+    fn read_record_too_short() {
+        let err = schema().read_record("malenia   0000").unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::LineTooShort {
+                expected: 24,
+                found: 14
+            }
+        ));
+    }
+
+    #[test]
+    fn read_record_too_long() {
+        let err = schema()
+            .read_record("malenia   00001337*rot**trailing")
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RecordError::LineTooLong {
+                expected: 24,
+                found: 32
+            }
+        ));
+    }
+
+    #[test]
+    fn writer_reader_roundtrip() {
+        let schema = schema();
+        let mut writer = RecordWriter::new(&schema, Vec::new());
+        writer.write_record(&["ranni", "4", "moon"]).unwrap();
+        writer.write_record(&["blaidd", "99", "wolf"]).unwrap();
+        let output = writer.into_inner();
+
+        let records: Vec<Vec<String>> = RecordReader::new(&schema, output.as_slice())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(vec!["ranni", "4", "moon"], records[0]);
+        assert_eq!(vec!["blaidd", "99", "wolf"], records[1]);
+        assert_eq!(2, records.len());
+    }
+
+    #[test]
+    fn reader_crlf() {
+        let schema = schema();
+        let input: &[u8] = b"ranni     00000004*moon*\r\nshort\r\n";
+        let mut reader = RecordReader::new(&schema, input);
+        assert_eq!(vec!["ranni", "4", "moon"], reader.next().unwrap().unwrap());
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(RecordError::LineTooShort { .. })
+        ));
+        assert!(reader.next().is_none());
+    }
+}
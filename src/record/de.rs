@@ -0,0 +1,218 @@
+use std::str::FromStr;
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+use super::{RecordError, RecordField};
+
+impl de::Error for RecordError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+/// Deserializes the stripped fields of a fixed-width line as a struct (keyed by the field
+/// names of the schema), or as a tuple or sequence (by position).
+pub(super) struct LineDeserializer<'s, 'de> {
+    fields: &'s [RecordField],
+    values: std::iter::Zip<std::slice::Iter<'s, RecordField>, std::vec::IntoIter<&'de str>>,
+    value: Option<(&'s RecordField, &'de str)>,
+}
+
+impl<'s, 'de> LineDeserializer<'s, 'de> {
+    pub(super) fn new(fields: &'s [RecordField], values: Vec<&'de str>) -> Self {
+        Self {
+            fields,
+            values: fields.iter().zip(values),
+            value: None,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut LineDeserializer<'_, 'de> {
+    type Error = RecordError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RecordError> {
+        visitor.visit_map(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RecordError> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, RecordError> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, RecordError> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, RecordError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> de::MapAccess<'de> for LineDeserializer<'_, 'de> {
+    type Error = RecordError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, RecordError> {
+        match self.values.next() {
+            Some((field, value)) => {
+                self.value = Some((field, value));
+                seed.deserialize(field.name.as_str().into_deserializer())
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, RecordError> {
+        let (field, value) = self
+            .value
+            .take()
+            .expect("next_value_seed was called before next_key_seed");
+        seed.deserialize(FieldDeserializer { field, value })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for LineDeserializer<'_, 'de> {
+    type Error = RecordError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, RecordError> {
+        match self.values.next() {
+            Some((field, value)) => seed
+                .deserialize(FieldDeserializer { field, value })
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+/// Deserializes a single stripped field value as a scalar.
+struct FieldDeserializer<'s, 'de> {
+    field: &'s RecordField,
+    value: &'de str,
+}
+
+impl FieldDeserializer<'_, '_> {
+    fn parse<T: FromStr>(&self, expected: &str) -> Result<T, RecordError> {
+        self.value.parse().map_err(|_| {
+            RecordError::Message(format!(
+                "invalid value '{}' for field '{}', expected {expected}",
+                self.value, self.field.name
+            ))
+        })
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RecordError> {
+                visitor.$visit(self.parse::<$ty>(stringify!($ty))?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for FieldDeserializer<'_, 'de> {
+    type Error = RecordError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RecordError> {
+        visitor.visit_borrowed_str(self.value)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool(bool),
+        deserialize_i8 => visit_i8(i8),
+        deserialize_i16 => visit_i16(i16),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_i64 => visit_i64(i64),
+        deserialize_i128 => visit_i128(i128),
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+        deserialize_u128 => visit_u128(u128),
+        deserialize_f32 => visit_f32(f32),
+        deserialize_f64 => visit_f64(f64),
+        deserialize_char => visit_char(char),
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RecordError> {
+        if self.value.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RecordError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, RecordError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, RecordError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, RecordError> {
+        visitor.visit_enum(self.value.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
@@ -0,0 +1,412 @@
+use std::fmt::Write;
+
+use serde::Serialize;
+use serde::ser::{self, Impossible};
+
+use super::{RecordError, RecordField, RecordSchema};
+
+impl ser::Error for RecordError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+/// Serializes a struct, tuple, or sequence of scalars into a fixed-width line, writing one
+/// field of the schema per scalar.
+pub(super) struct LineSerializer<'s, 'b> {
+    schema: &'s RecordSchema,
+    buffer: &'b mut String,
+    scratch: String,
+    idx: usize,
+}
+
+impl<'s, 'b> LineSerializer<'s, 'b> {
+    pub(super) fn new(schema: &'s RecordSchema, buffer: &'b mut String) -> Self {
+        Self {
+            schema,
+            buffer,
+            scratch: String::new(),
+            idx: 0,
+        }
+    }
+
+    /// Serializes the `value` into the next field of the schema. If a `name` is given it has
+    /// to match the name of the field.
+    fn serialize_next<T>(&mut self, name: Option<&str>, value: &T) -> Result<(), RecordError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let field: &RecordField = match self.schema.fields.get(self.idx) {
+            Some(field) => field,
+            None => {
+                return Err(RecordError::FieldCount {
+                    expected: self.schema.fields.len(),
+                    found: self.idx + 1,
+                });
+            }
+        };
+
+        if let Some(name) = name.filter(|name| *name != field.name) {
+            return Err(RecordError::UnexpectedField {
+                expected: field.name.clone(),
+                found: name.to_string(),
+            });
+        }
+
+        value.serialize(FieldSerializer {
+            field,
+            buffer: self.buffer,
+            scratch: &mut self.scratch,
+        })?;
+        self.idx += 1;
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), RecordError> {
+        if self.idx != self.schema.fields.len() {
+            return Err(RecordError::FieldCount {
+                expected: self.schema.fields.len(),
+                found: self.idx,
+            });
+        }
+        Ok(())
+    }
+
+    fn unsupported(&self) -> RecordError {
+        RecordError::Message(String::from(
+            "only structs, tuples, and sequences of scalars can be serialized into a record",
+        ))
+    }
+}
+
+macro_rules! unsupported_top_level {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<Self::Ok, Self::Error> {
+                Err(self.unsupported())
+            }
+        )*
+    };
+}
+
+impl<'a, 's, 'b> ser::Serializer for &'a mut LineSerializer<'s, 'b> {
+    type Ok = ();
+    type Error = RecordError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Impossible<(), RecordError>;
+    type SerializeMap = Impossible<(), RecordError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), RecordError>;
+
+    unsupported_top_level! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), RecordError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), RecordError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, RecordError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, RecordError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, RecordError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, RecordError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, RecordError> {
+        Err(self.unsupported())
+    }
+}
+
+impl ser::SerializeSeq for &mut LineSerializer<'_, '_> {
+    type Ok = ();
+    type Error = RecordError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), RecordError> {
+        self.serialize_next(None, value)
+    }
+
+    fn end(self) -> Result<(), RecordError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for &mut LineSerializer<'_, '_> {
+    type Ok = ();
+    type Error = RecordError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), RecordError> {
+        self.serialize_next(None, value)
+    }
+
+    fn end(self) -> Result<(), RecordError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut LineSerializer<'_, '_> {
+    type Ok = ();
+    type Error = RecordError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), RecordError> {
+        self.serialize_next(None, value)
+    }
+
+    fn end(self) -> Result<(), RecordError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for &mut LineSerializer<'_, '_> {
+    type Ok = ();
+    type Error = RecordError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), RecordError> {
+        self.serialize_next(Some(key), value)
+    }
+
+    fn end(self) -> Result<(), RecordError> {
+        self.finish()
+    }
+}
+
+/// Serializes a single scalar value into its padded field.
+struct FieldSerializer<'f, 'b> {
+    field: &'f RecordField,
+    buffer: &'b mut String,
+    scratch: &'b mut String,
+}
+
+impl FieldSerializer<'_, '_> {
+    /// Formats the `value` into the scratch buffer and writes it into its padded field.
+    fn write_display<T: std::fmt::Display>(self, value: T) -> Result<(), RecordError> {
+        self.scratch.clear();
+        write!(self.scratch, "{value}").expect("a Display implementation returned an error");
+        self.field.write(self.scratch, self.buffer)
+    }
+
+    fn unsupported(&self) -> RecordError {
+        RecordError::Message(format!(
+            "field '{}' can not be serialized, only scalar values are supported",
+            self.field.name
+        ))
+    }
+}
+
+macro_rules! serialize_display {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+                self.write_display(value)
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for FieldSerializer<'_, '_> {
+    type Ok = ();
+    type Error = RecordError;
+    type SerializeSeq = Impossible<(), RecordError>;
+    type SerializeTuple = Impossible<(), RecordError>;
+    type SerializeTupleStruct = Impossible<(), RecordError>;
+    type SerializeTupleVariant = Impossible<(), RecordError>;
+    type SerializeMap = Impossible<(), RecordError>;
+    type SerializeStruct = Impossible<(), RecordError>;
+    type SerializeStructVariant = Impossible<(), RecordError>;
+
+    serialize_display! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), RecordError> {
+        self.field.write(value, self.buffer)
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> Result<(), RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_none(self) -> Result<(), RecordError> {
+        self.field.write("", self.buffer)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), RecordError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), RecordError> {
+        self.field.write("", self.buffer)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), RecordError> {
+        self.field.write("", self.buffer)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), RecordError> {
+        self.field.write(variant, self.buffer)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), RecordError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, RecordError> {
+        Err(self.unsupported())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, RecordError> {
+        Err(self.unsupported())
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::VecDeque;
+use std::io::{Cursor, Write};
+
+use crate::alignment::Alignment;
+use crate::metric::{CharWidth, pad_metric_to_sink};
+use crate::source::Source;
+use crate::utf8::Utf8Buffer;
+use crate::width::Width;
+
+/// The number of bytes of pad symbols written to a cursor at once.
+const N_BYTES_CHUNK: usize = 64;
+
+/// A trait representing any output which padded content can be appended to, e.g., a [`Vec`],
+/// a [`VecDeque`], a [`Cursor`], or a user-defined arena.
+///
+/// The sink receives the padded content as runs of `symbol`s and slices of content `C`, which
+/// is `[S]` for slices and vectors, and [`str`] for strings (with `S` being [`char`]).
+///
+/// Every [`Utf8Buffer`] is a sink for strings.
+pub trait PadSink<S, C: ?Sized = [S]> {
+    /// Reserves capacity for at least `additional` more units, i.e., items for slices and bytes
+    /// for strings.
+    fn reserve(&mut self, additional: usize);
+
+    /// Appends `n` copies of the `symbol`.
+    fn push_run(&mut self, symbol: S, n: usize);
+
+    /// Appends the (possibly truncated) `content`.
+    fn push_slice(&mut self, content: &C);
+}
+
+impl<T: Clone> PadSink<T> for Vec<T> {
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
+    fn push_run(&mut self, symbol: T, n: usize) {
+        self.extend(std::iter::repeat_n(symbol, n));
+    }
+
+    fn push_slice(&mut self, content: &[T]) {
+        self.extend_from_slice(content);
+    }
+}
+
+impl<T: Clone> PadSink<T> for VecDeque<T> {
+    fn reserve(&mut self, additional: usize) {
+        VecDeque::reserve(self, additional);
+    }
+
+    fn push_run(&mut self, symbol: T, n: usize) {
+        self.extend(std::iter::repeat_n(symbol, n));
+    }
+
+    fn push_slice(&mut self, content: &[T]) {
+        self.extend(content.iter().cloned());
+    }
+}
+
+/// Writes at the current position of the cursor, overwriting any existing bytes and growing the
+/// underlying vector as needed.
+impl PadSink<u8> for Cursor<Vec<u8>> {
+    fn reserve(&mut self, additional: usize) {
+        self.get_mut().reserve(additional);
+    }
+
+    fn push_run(&mut self, symbol: u8, n: usize) {
+        let chunk = [symbol; N_BYTES_CHUNK];
+        let mut n_bytes: usize = n;
+        while n_bytes > 0 {
+            let n_bytes_chunk: usize = n_bytes.min(N_BYTES_CHUNK);
+            self.write_all(&chunk[..n_bytes_chunk])
+                .expect("writing to a Cursor<Vec<u8>> should never fail");
+            n_bytes -= n_bytes_chunk;
+        }
+    }
+
+    fn push_slice(&mut self, content: &[u8]) {
+        self.write_all(content)
+            .expect("writing to a Cursor<Vec<u8>> should never fail");
+    }
+}
+
+impl<B: Utf8Buffer + ?Sized> PadSink<char, str> for B {
+    fn reserve(&mut self, additional: usize) {
+        self.reserve_bytes(additional);
+    }
+
+    fn push_run(&mut self, symbol: char, n: usize) {
+        (0..n).for_each(|_| self.push_char(symbol));
+    }
+
+    fn push_slice(&mut self, content: &str) {
+        self.extend_utf8(content);
+    }
+}
+
+/// A companion trait to [`Source`] for types which can pad into any [`PadSink`], and not only
+/// into their associated `Buffer`.
+///
+/// [`Source::pad_to_buffer`] is a thin wrapper around [`SinkSource::pad_to_sink`] for the std
+/// types.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+/// use std::collections::VecDeque;
+///
+/// let mut queue: VecDeque<u8> = VecDeque::from([9u8]);
+/// [1u8, 2].as_slice().pad_to_sink(4, Alignment::Center, 0u8, &mut queue);
+/// assert_eq!(VecDeque::from([9u8, 0, 1, 2, 0]), queue);
+/// ```
+pub trait SinkSource: Source {
+    /// The content type pushed to the sink, e.g., [`str`] or `[T]`.
+    type Content: ?Sized;
+
+    /// Pads (or truncates) to the specified `width` using the given `symbol` according to the
+    /// specified alignment `mode`, appending the result to the `sink`.
+    fn pad_to_sink<K: PadSink<Self::Symbol, Self::Content> + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        sink: &mut K,
+    );
+}
+
+/// Pads (or truncates) the `s` into the `sink`.
+fn pad_slice_to_sink<T: Clone + Copy, K: PadSink<T> + ?Sized>(
+    s: &[T],
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: T,
+    sink: &mut K,
+) {
+    let width: usize = width.into().resolve(s.len());
+    if width < s.len() {
+        sink.push_slice(s.truncate_to_fit(width, mode));
+        return;
+    }
+
+    let pads = mode.pads(width - s.len());
+    sink.reserve(width);
+    sink.push_run(symbol, pads.left());
+    sink.push_slice(s);
+    sink.push_run(symbol, pads.right());
+}
+
+impl SinkSource for &str {
+    type Content = str;
+
+    fn pad_to_sink<K: PadSink<char, str> + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        sink: &mut K,
+    ) {
+        pad_metric_to_sink(self, width, mode, symbol, &CharWidth, sink);
+    }
+}
+
+impl SinkSource for String {
+    type Content = str;
+
+    fn pad_to_sink<K: PadSink<char, str> + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        sink: &mut K,
+    ) {
+        pad_metric_to_sink(self, width, mode, symbol, &CharWidth, sink);
+    }
+}
+
+impl<T> SinkSource for Vec<T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Content = [T];
+
+    fn pad_to_sink<K: PadSink<T> + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: T,
+        sink: &mut K,
+    ) {
+        pad_slice_to_sink(self, width, mode, symbol, sink);
+    }
+}
+
+impl<T> SinkSource for &[T]
+where
+    T: Clone + Copy + Sized,
+{
+    type Content = [T];
+
+    fn pad_to_sink<K: PadSink<T> + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: T,
+        sink: &mut K,
+    ) {
+        pad_slice_to_sink(self, width, mode, symbol, sink);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An arena which only records the pushed runs and slices.
+    #[derive(Default)]
+    struct Arena {
+        ops: Vec<String>,
+    }
+
+    impl PadSink<char, str> for Arena {
+        fn reserve(&mut self, _additional: usize) {}
+
+        fn push_run(&mut self, symbol: char, n: usize) {
+            self.ops.push(format!("{n}x{symbol}"));
+        }
+
+        fn push_slice(&mut self, content: &str) {
+            self.ops.push(content.to_string());
+        }
+    }
+
+    #[test]
+    fn str_to_custom_sink() {
+        let mut arena = Arena::default();
+        "solaire".pad_to_sink(10, Alignment::Center, '☀', &mut arena);
+        assert_eq!(vec!["1x☀", "solaire", "2x☀"], arena.ops);
+    }
+
+    #[test]
+    fn string_to_vec_u8() {
+        let mut buffer: Vec<u8> = Vec::new();
+        String::from("ツfinger").pad_to_sink(9, Alignment::Right, '.', &mut buffer);
+        assert_eq!("..ツfinger".as_bytes(), buffer.as_slice());
+    }
+
+    #[test]
+    fn slice_to_vec_deque_truncated() {
+        let mut queue: VecDeque<u16> = VecDeque::new();
+        vec![1u16, 2, 3, 4, 5].pad_to_sink(3, Alignment::Right, 0, &mut queue);
+        assert_eq!(VecDeque::from([3u16, 4, 5]), queue);
+    }
+
+    #[test]
+    fn slice_to_cursor_overwrites() {
+        let mut cursor = Cursor::new(vec![b'x'; 6]);
+        cursor.set_position(2);
+        b"ab"
+            .as_slice()
+            .pad_to_sink(3, Alignment::Left, b' ', &mut cursor);
+        assert_eq!(b"xxab x", cursor.get_ref().as_slice());
+        assert_eq!(5, cursor.position());
+    }
+
+    #[test]
+    fn slice_to_cursor_long_run() {
+        let mut cursor = Cursor::new(Vec::new());
+        [1u8]
+            .as_slice()
+            .pad_to_sink(150, Alignment::Center, 0, &mut cursor);
+        let mut expected: Vec<u8> = vec![0u8; 150];
+        expected[74] = 1;
+        assert_eq!(expected, cursor.into_inner());
+    }
+
+    #[test]
+    fn str_to_cursor_appends() {
+        let mut cursor = Cursor::new(Vec::new());
+        "ab".pad_to_sink(Width::AtLeast(4), Alignment::Left, '-', &mut cursor);
+        "cd".pad_to_sink(3, Alignment::Right, '-', &mut cursor);
+        assert_eq!(b"ab---cd", cursor.get_ref().as_slice());
+    }
+}
@@ -1,5 +1,9 @@
 #![allow(clippy::needless_doctest_main)]
 use crate::alignment::Alignment;
+use crate::metric::{CharWidth, WidthMetric, metric_range, pad_metric_to_sink};
+use crate::outcome::{OutcomeSource, PadOutcome};
+use crate::sink::SinkSource;
+use crate::width::Width;
 
 /// A trait representing a width-aware, read-only data buffer that can be padded (and truncated).
 ///
@@ -10,7 +14,8 @@ use crate::alignment::Alignment;
 ///
 /// # Associated Types
 /// - `Symbol`: the element used for padding (e.g., `char`, `u8`, or anything that implements [`Clone`], [`Copy`], and [`Debug`]).
-/// - `Buffer`: a mutable buffer type that is used when calling [`pad_to_buffer`].
+/// - `Buffer`: a mutable buffer type that is used when calling [`pad_to_buffer`]. To pad into
+///   any other buffer, see [`crate::SinkSource`].
 /// - `Output`: the owned result of the padding operations.
 /// - `Slice<'a>`: a borrowed view into the possibly truncated buffer.
 ///
@@ -31,13 +36,17 @@ pub trait Source {
 
     /// Pads the buffer to the specified `width` using the given `symbol` according to the
     /// specified alignment `mode`.
-    fn pad(&self, width: usize, mode: Alignment, symbol: Self::Symbol) -> Self::Output;
+    ///
+    /// The `width` is either an exact `usize` or any [`Width`] strategy.
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output;
 
     /// Performs in-place padding of `width` amount of `symbols` according to the specified
     /// alignment `mode` into the provided `buffer`.
+    ///
+    /// The `width` is either an exact `usize` or any [`Width`] strategy.
     fn pad_to_buffer(
         &self,
-        width: usize,
+        width: impl Into<Width>,
         mode: Alignment,
         symbol: Self::Symbol,
         buffer: &mut Self::Buffer,
@@ -59,42 +68,7 @@ impl Source for &str {
     /// - [`Alignment::Right`]: truncates from the left.
     /// - [`Alignment::Center`]: truncates equally from both ends (extra char is removed from the left if the number of chars to truncate is odd).
     fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
-        let mut st_byte: usize = 0;
-        let mut ed_byte: usize = self.len();
-
-        match mode {
-            Alignment::Left => {
-                ed_byte = self
-                    .char_indices()
-                    .nth(width)
-                    .map(|(byte_offset, _)| byte_offset)
-                    .expect("the &str did not contain enough chars!");
-            }
-            Alignment::Right => {
-                st_byte = self
-                    .char_indices()
-                    .rev()
-                    .nth(width - 1)
-                    .map(|(byte_offset, _)| byte_offset)
-                    .expect("the &str did not contain enough chars!");
-            }
-            Alignment::Center => {
-                let st_idx: usize = (self.chars().count() - width) / 2;
-                let ed_idx: usize = st_idx + width;
-
-                for (idx, (byte_offset, _)) in self.char_indices().enumerate() {
-                    if idx == st_idx {
-                        st_byte = byte_offset;
-                        continue;
-                    }
-                    if idx == ed_idx {
-                        ed_byte = byte_offset;
-                        break;
-                    }
-                }
-            }
-        };
-
+        let (st_byte, ed_byte) = metric_range(self, width, mode, &CharWidth);
         &self[st_byte..ed_byte]
     }
 
@@ -120,25 +94,9 @@ impl Source for &str {
     /// assert_eq!("🦔øĸœ🦔🦔", o2);
     /// assert_eq!(18, o2.len());
     /// ```
-    fn pad(&self, width: usize, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
-        let n_chars_original: usize = self.chars().count();
-        if width < n_chars_original {
-            return self.truncate_to_fit(width, mode).to_string();
-        }
-
-        let n_chars_diff: usize = width - n_chars_original;
-        if n_chars_diff == 0 {
-            return self.to_string();
-        }
-
-        let n_bytes_required: usize = self.len() + n_chars_diff * symbol.len_utf8();
-        let mut output = String::with_capacity(n_bytes_required);
-
-        let pads = mode.pads(n_chars_diff);
-        (0..pads.left()).for_each(|_| output.push(symbol));
-        output.push_str(self);
-        (0..pads.right()).for_each(|_| output.push(symbol));
-
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        let mut output = String::new();
+        pad_metric_to_sink(self, width, mode, symbol, &CharWidth, &mut output);
         output
     }
 
@@ -172,27 +130,66 @@ impl Source for &str {
     /// ```
     fn pad_to_buffer(
         &self,
-        width: usize,
+        width: impl Into<Width>,
         mode: Alignment,
         symbol: Self::Symbol,
         buffer: &mut Self::Buffer,
     ) {
-        let n_chars_original: usize = self.chars().count();
-        if width < n_chars_original {
-            buffer.push_str(self.truncate_to_fit(width, mode));
-            return;
-        }
+        self.pad_to_sink(width, mode, symbol, buffer);
+    }
+}
 
-        let n_chars_diff: usize = width - n_chars_original;
-        if n_chars_diff == 0 {
-            buffer.push_str(self);
-            return;
-        }
+impl OutcomeSource for &str {
+    /// Pads or truncates the &str like [`Source::pad`], additionally returning a [`PadOutcome`].
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let (o, outcome) = "bonfire".pad_with_outcome(10, Alignment::Right, '🔥');
+    /// assert_eq!("🔥🔥🔥bonfire", o);
+    /// assert_eq!(3..10, outcome.content);
+    /// assert_eq!(12..19, outcome.bytes);
+    /// ```
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let n_chars_original: usize = CharWidth.measure(self);
+        let width: usize = width.into().resolve(n_chars_original);
+        let output: String = self.pad(width, mode, symbol);
+        let outcome = PadOutcome::new(n_chars_original, width, mode)
+            .with_bytes(symbol.len_utf8(), output.len());
+        (output, outcome)
+    }
 
-        let pads = mode.pads(n_chars_diff);
-        (0..pads.left()).for_each(|_| buffer.push(symbol));
-        buffer.push_str(self);
-        (0..pads.right()).for_each(|_| buffer.push(symbol));
+    /// Pads or truncates the &str into the `buffer` like [`Source::pad_to_buffer`], additionally
+    /// returning a [`PadOutcome`] relative to the length of the `buffer` before the call.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let mut buffer = String::from("ashen one: ");
+    /// let outcome = "¡hola!".pad_to_buffer_with_outcome(8, Alignment::Left, '-', &mut buffer);
+    /// assert_eq!("ashen one: ¡hola!--", buffer);
+    /// assert_eq!("¡hola!", &buffer[11..][outcome.bytes]);
+    /// ```
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        let n_chars_original: usize = CharWidth.measure(self);
+        let width: usize = width.into().resolve(n_chars_original);
+        let n_bytes_buffer: usize = buffer.len();
+        self.pad_to_buffer(width, mode, symbol, buffer);
+        PadOutcome::new(n_chars_original, width, mode)
+            .with_bytes(symbol.len_utf8(), buffer.len() - n_bytes_buffer)
     }
 }
 
@@ -211,43 +208,7 @@ impl Source for String {
     /// - [`Alignment::Right`]: truncates from the left.
     /// - [`Alignment::Center`]: truncates equally from both ends (extra char is removed from the left if the number of chars to truncate is odd).
     fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
-        let mut st_byte: usize = 0;
-        let mut ed_byte: usize = self.len();
-
-        match mode {
-            Alignment::Left => {
-                ed_byte = self
-                    .char_indices()
-                    .nth(width)
-                    .map(|(byte_offset, _)| byte_offset)
-                    .expect("the String did not contain enough chars!");
-            }
-            Alignment::Right => {
-                st_byte = self
-                    .char_indices()
-                    .rev()
-                    .nth(width - 1)
-                    .map(|(byte_offset, _)| byte_offset)
-                    .expect("the String did not contain enough chars!");
-            }
-            Alignment::Center => {
-                let st_idx: usize = (self.chars().count() - width) / 2;
-                let ed_idx: usize = st_idx + width;
-
-                for (idx, (byte_offset, _)) in self.char_indices().enumerate() {
-                    if idx == st_idx {
-                        st_byte = byte_offset;
-                        continue;
-                    }
-                    if idx == ed_idx {
-                        ed_byte = byte_offset;
-                        break;
-                    }
-                }
-            }
-        };
-
-        &self[st_byte..ed_byte]
+        self.as_str().truncate_to_fit(width, mode)
     }
 
     /// Pads or truncates the string to match the specified `width` according to the specified alignment `mode`.
@@ -266,28 +227,8 @@ impl Source for String {
     /// assert_eq!("風風風風hobbit", o);
     /// assert_eq!(18, o.len());
     /// ```
-    fn pad(&self, width: usize, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
-        let n_chars_original: usize = self.chars().count();
-        if width < n_chars_original {
-            return self.truncate_to_fit(width, mode).to_string();
-        }
-
-        let n_chars_diff: usize = width - n_chars_original;
-        if n_chars_diff == 0 {
-            return self.clone();
-        }
-
-        let pads = mode.pads(n_chars_diff);
-        let n_bytes_required: usize = self.len() + n_chars_diff * symbol.len_utf8();
-
-        // This is ~50% faster for small strings compared to using `std::iter::repeat_n`, and for
-        // large strings (>20_000) we still get about ~10% increased performance.
-        // Most likely llvm optimization magic :)
-        let mut output = String::with_capacity(n_bytes_required);
-        (0..pads.left()).for_each(|_| output.push(symbol));
-        output.push_str(self);
-        (0..pads.right()).for_each(|_| output.push(symbol));
-        output
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        self.as_str().pad(width, mode, symbol)
     }
 
     /// Pads or truncates the string in-place to match the specified `width` according to the
@@ -320,30 +261,53 @@ impl Source for String {
     /// ```
     fn pad_to_buffer(
         &self,
-        width: usize,
+        width: impl Into<Width>,
         mode: Alignment,
         symbol: Self::Symbol,
         buffer: &mut Self::Buffer,
     ) {
-        let n_chars_original: usize = self.chars().count();
-        if width < n_chars_original {
-            buffer.push_str(self.truncate_to_fit(width, mode));
-            return;
-        }
-
-        let n_chars_diff: usize = width - n_chars_original;
-        if n_chars_diff == 0 {
-            buffer.push_str(self);
-            return;
-        }
+        self.pad_to_sink(width, mode, symbol, buffer);
+    }
+}
 
-        let pads = mode.pads(n_chars_diff);
-        (0..pads.left()).for_each(|_| buffer.push(symbol));
-        buffer.push_str(self);
-        (0..pads.right()).for_each(|_| buffer.push(symbol));
+impl OutcomeSource for String {
+    /// Pads or truncates the string like [`Source::pad`], additionally returning a [`PadOutcome`].
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let n_chars_original: usize = CharWidth.measure(self);
+        let width: usize = width.into().resolve(n_chars_original);
+        let output: String = self.pad(width, mode, symbol);
+        let outcome = PadOutcome::new(n_chars_original, width, mode)
+            .with_bytes(symbol.len_utf8(), output.len());
+        (output, outcome)
+    }
+
+    /// Pads or truncates the string into the `buffer` like [`Source::pad_to_buffer`], additionally
+    /// returning a [`PadOutcome`] relative to the length of the `buffer` before the call.
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        let n_chars_original: usize = CharWidth.measure(self);
+        let width: usize = width.into().resolve(n_chars_original);
+        let n_bytes_buffer: usize = buffer.len();
+        self.pad_to_buffer(width, mode, symbol, buffer);
+        PadOutcome::new(n_chars_original, width, mode)
+            .with_bytes(symbol.len_utf8(), buffer.len() - n_bytes_buffer)
     }
 }
 
+/// Pads and truncates the vector item by item, e.g., code unit by code unit for UTF-16 encoded
+/// text in a `Vec<u16>`, which may split a surrogate pair: use [`Utf16Source`](crate::Utf16Source)
+/// for those instead. Likewise, a `Vec<u8>` of UTF-8 encoded text may be cut within a char: pad
+/// the string itself with [`ByteWidthSource`](crate::ByteWidthSource) to a width in bytes.
 impl<T> Source for Vec<T>
 where
     T: Clone + Copy + Sized,
@@ -362,8 +326,8 @@ where
     /// - [`Alignment::Center`]: truncates equally from both ends (extra item is removed from the left if the number of items to truncate is odd).
     fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
         match mode {
-            Alignment::Left => &self[..width],
-            Alignment::Right => &self[(self.len() - width)..],
+            Alignment::Left | Alignment::Start => &self[..width],
+            Alignment::Right | Alignment::End => &self[(self.len() - width)..],
             Alignment::Center => {
                 let st_idx: usize = (self.len() - width) / 2;
                 let ed_idx: usize = st_idx + width;
@@ -388,7 +352,8 @@ where
     /// assert_eq!(Vec::from(&["scooby", "doo", "!!", "!!", "!!"]), o);
     /// assert_eq!(5, o.len());
     /// ```
-    fn pad(&self, width: usize, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        let width: usize = width.into().resolve(self.len());
         if width < self.len() {
             return self.truncate_to_fit(width, mode).to_vec();
         }
@@ -456,29 +421,67 @@ where
     /// ```
     fn pad_to_buffer(
         &self,
-        width: usize,
+        width: impl Into<Width>,
         mode: Alignment,
         symbol: Self::Symbol,
         buffer: &mut Self::Buffer,
     ) {
-        if width < self.len() {
-            buffer.extend_from_slice(self.truncate_to_fit(width, mode));
-            return;
-        }
+        self.pad_to_sink(width, mode, symbol, buffer);
+    }
+}
 
-        let n_items_diff: usize = width - self.len();
-        if n_items_diff == 0 {
-            buffer.extend_from_slice(self);
-            return;
-        }
+impl<T> OutcomeSource for Vec<T>
+where
+    T: Clone + Copy + Sized,
+{
+    /// Pads or truncates the vector like [`Source::pad`], additionally returning a [`PadOutcome`].
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let v: Vec<u16> = Vec::from(&[1u16, 2, 3, 4, 5]);
+    /// let (o, outcome) = v.pad_with_outcome(2, Alignment::Center, 0u16);
+    /// assert_eq!(Vec::from(&[2u16, 3]), o);
+    /// assert_eq!(Pads(1, 2), outcome.truncated);
+    /// assert_eq!(0..4, outcome.bytes);
+    /// ```
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let width: usize = width.into().resolve(self.len());
+        let output: Vec<T> = self.pad(width, mode, symbol);
+        let outcome = PadOutcome::new(self.len(), width, mode)
+            .with_bytes(size_of::<T>(), output.len() * size_of::<T>());
+        (output, outcome)
+    }
 
-        let pads = mode.pads(n_items_diff);
-        buffer.extend_from_slice(&std::iter::repeat_n(symbol, pads.left()).collect::<Vec<T>>());
-        buffer.extend_from_slice(self);
-        buffer.resize(width, symbol);
+    /// Pads or truncates the vector into the `buffer` like [`Source::pad_to_buffer`], additionally
+    /// returning a [`PadOutcome`] relative to the length of the `buffer` before the call.
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        let width: usize = width.into().resolve(self.len());
+        let n_items_buffer: usize = buffer.len();
+        self.pad_to_buffer(width, mode, symbol, buffer);
+        PadOutcome::new(self.len(), width, mode).with_bytes(
+            size_of::<T>(),
+            (buffer.len() - n_items_buffer) * size_of::<T>(),
+        )
     }
 }
 
+/// Pads and truncates the slice item by item, e.g., code unit by code unit for UTF-16 encoded
+/// text in a `&[u16]`, which may split a surrogate pair: use [`Utf16Source`](crate::Utf16Source)
+/// for those instead. Likewise, a `&[u8]` of UTF-8 encoded text may be cut within a char: pad
+/// the string itself with [`ByteWidthSource`](crate::ByteWidthSource) to a width in bytes.
 impl<T> Source for &[T]
 where
     T: Clone + Copy + Sized,
@@ -487,7 +490,7 @@ where
     type Buffer = Vec<T>;
     type Output = Vec<T>;
     type Slice<'a>
-        = &'a [T]
+        = Self
     where
         Self: 'a;
 
@@ -497,8 +500,8 @@ where
     /// - [`Alignment::Center`]: truncates equally from both ends (extra item is removed from the left if the number of items to truncate is odd).
     fn truncate_to_fit<'a>(&'a self, width: usize, mode: Alignment) -> Self::Slice<'a> {
         match mode {
-            Alignment::Left => &self[..width],
-            Alignment::Right => &self[(self.len() - width)..],
+            Alignment::Left | Alignment::Start => &self[..width],
+            Alignment::Right | Alignment::End => &self[(self.len() - width)..],
             Alignment::Center => {
                 let st_idx: usize = (self.len() - width) / 2;
                 let ed_idx: usize = st_idx + width;
@@ -523,7 +526,8 @@ where
     /// assert_eq!(Vec::from(&[1, 2, 3, 4, 1337, 1337, 1337, 1337, 1337]), o);
     /// assert_eq!(9, o.len());
     /// ```
-    fn pad(&self, width: usize, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+    fn pad(&self, width: impl Into<Width>, mode: Alignment, symbol: Self::Symbol) -> Self::Output {
+        let width: usize = width.into().resolve(self.len());
         if width < self.len() {
             return self.truncate_to_fit(width, mode).to_vec();
         }
@@ -565,26 +569,49 @@ where
     /// ```
     fn pad_to_buffer(
         &self,
-        width: usize,
+        width: impl Into<Width>,
         mode: Alignment,
         symbol: Self::Symbol,
         buffer: &mut Self::Buffer,
     ) {
-        if width < self.len() {
-            buffer.extend_from_slice(self.truncate_to_fit(width, mode));
-            return;
-        }
+        self.pad_to_sink(width, mode, symbol, buffer);
+    }
+}
 
-        let n_items_diff: usize = width - self.len();
-        if n_items_diff == 0 {
-            buffer.extend_from_slice(self);
-            return;
-        }
+impl<T> OutcomeSource for &[T]
+where
+    T: Clone + Copy + Sized,
+{
+    /// Pads or truncates the slice like [`Source::pad`], additionally returning a [`PadOutcome`].
+    fn pad_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+    ) -> (Self::Output, PadOutcome) {
+        let width: usize = width.into().resolve(self.len());
+        let output: Vec<T> = self.pad(width, mode, symbol);
+        let outcome = PadOutcome::new(self.len(), width, mode)
+            .with_bytes(size_of::<T>(), output.len() * size_of::<T>());
+        (output, outcome)
+    }
 
-        let pads = mode.pads(n_items_diff);
-        buffer.extend_from_slice(&std::iter::repeat_n(symbol, pads.left()).collect::<Vec<T>>());
-        buffer.extend_from_slice(self);
-        buffer.resize(width, symbol);
+    /// Pads or truncates the slice into the `buffer` like [`Source::pad_to_buffer`], additionally
+    /// returning a [`PadOutcome`] relative to the length of the `buffer` before the call.
+    fn pad_to_buffer_with_outcome(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: Self::Symbol,
+        buffer: &mut Self::Buffer,
+    ) -> PadOutcome {
+        let width: usize = width.into().resolve(self.len());
+        let n_items_buffer: usize = buffer.len();
+        self.pad_to_buffer(width, mode, symbol, buffer);
+        PadOutcome::new(self.len(), width, mode).with_bytes(
+            size_of::<T>(),
+            (buffer.len() - n_items_buffer) * size_of::<T>(),
+        )
     }
 }
 
@@ -1036,6 +1063,17 @@ mod tests_vec {
         assert_eq!(expected.len(), buffer.len());
         assert_eq!(expected, buffer);
     }
+
+    #[test]
+    fn pad_to_buffer_non_empty() {
+        let width: usize = 4;
+        let source = Vec::from(&[1u8, 2]);
+        let mut buffer = Vec::from(&[7u8, 7]);
+        source.pad_to_buffer(width, Alignment::Left, 0u8, &mut buffer);
+        let expected = Vec::from(&[7u8, 7, 1, 2, 0, 0]);
+        assert_eq!(expected.len(), buffer.len());
+        assert_eq!(expected, buffer);
+    }
 }
 
 #[cfg(test)]
@@ -1168,4 +1206,15 @@ mod tests_slice {
         assert_eq!(expected, buffer);
         assert_eq!(expected.len(), buffer.len());
     }
+
+    #[test]
+    fn pad_to_buffer_non_empty() {
+        let width = 5;
+        let source: &[char] = &['a', 'b', 'c'];
+        let mut buffer: Vec<char> = Vec::from(&['x']);
+        source.pad_to_buffer(width, Alignment::Center, ' ', &mut buffer);
+        let expected = Vec::from(&['x', ' ', 'a', 'b', 'c', ' ']);
+        assert_eq!(expected, buffer);
+        assert_eq!(expected.len(), buffer.len());
+    }
 }
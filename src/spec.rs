@@ -0,0 +1,536 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::alignment::Alignment;
+use crate::truncation::{TruncatingSource, Truncation};
+use crate::width::Width;
+
+/// A runtime representation of a [`std::fmt`]-style fill/align/width/precision specification,
+/// e.g., `*^20` or `<8.5`, that can be used to pad strings.
+///
+/// The specification is parsed from strings on the form `[[fill]align][width]['.' precision]`:
+/// - `fill`: the padding symbol (any char, defaults to `' '`), requires `align` to be present.
+/// - `align`: one of `<` ([`Alignment::Left`]), `^` ([`Alignment::Center`]), or `>`
+///   ([`Alignment::Right`]), defaults to [`Alignment::Left`] like [`std::fmt`] does for strings.
+///   There are no spec chars for [`Alignment::Start`] and [`Alignment::End`], which are
+///   formatted as `<` and `>` and hence parsed back as [`Alignment::Left`] and
+///   [`Alignment::Right`].
+/// - `width`: the width (in units of the padded source, e.g., utf8 chars for strings) to pad or
+///   truncate to (required, at least 1).
+/// - `precision`: the maximum number of chars to keep from the start of the content before
+///   padding it to `width`.
+///
+/// Contrary to [`std::fmt`], content that is wider than `width` is truncated according to the
+/// alignment mode and the [`Truncation`] strategy of the specification, which is not part of
+/// the string form and defaults to [`Truncation::Cut`], see [`FormatSpec::with_truncation`].
+///
+/// With the `serde` feature enabled the specification is (de)serialized as its string form, or
+/// as a map of the string form and the strategy if it is not [`Truncation::Cut`]:
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use padder::*;
+///
+/// let json = r#"["<12", { "spec": ".>12", "truncation": "elide_path" }]"#;
+/// let specs: Vec<FormatSpec> = serde_json::from_str(json).unwrap();
+/// assert_eq!(Truncation::Cut, specs[0].truncation());
+/// assert_eq!("…/bonfire.rs", specs[1].pad("src/firelink/bonfire.rs"));
+/// # }
+/// ```
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let spec: FormatSpec = "*^20".parse().unwrap();
+/// assert_eq!('*', spec.fill());
+/// assert_eq!(Alignment::Center, spec.mode());
+/// assert_eq!(20, spec.width());
+/// assert_eq!("******bonfire*******", spec.pad("bonfire"));
+///
+/// let spec: FormatSpec = "<8.5".parse().unwrap();
+/// assert_eq!("estus   ", spec.pad("estus flask"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormatSpec {
+    fill: char,
+    mode: Alignment,
+    width: usize,
+    precision: Option<usize>,
+    truncation: Truncation,
+}
+
+impl FormatSpec {
+    /// Creates a new specification without a precision which cuts wide content, returning a
+    /// [`ParseSpecError::InvalidWidth`] error if the `width` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let spec = FormatSpec::new('-', Alignment::Right, 4).unwrap();
+    /// assert_eq!("--ds", spec.pad("ds"));
+    ///
+    /// assert!(FormatSpec::new('-', Alignment::Right, 0).is_err());
+    /// ```
+    pub fn new(fill: char, mode: Alignment, width: usize) -> Result<Self, ParseSpecError> {
+        if width == 0 {
+            return Err(ParseSpecError::InvalidWidth(width.to_string()));
+        }
+        Ok(Self {
+            fill,
+            mode,
+            width,
+            precision: None,
+            truncation: Truncation::default(),
+        })
+    }
+
+    /// Get the padding symbol.
+    pub fn fill(&self) -> char {
+        self.fill
+    }
+
+    /// Get the alignment mode.
+    pub fn mode(&self) -> Alignment {
+        self.mode
+    }
+
+    /// Get the width to pad (or truncate) to, which is at least 1.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the maximum number of units kept from the start of the content, if any.
+    pub fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Get the strategy used to truncate content wider than `width`.
+    pub fn truncation(&self) -> Truncation {
+        self.truncation
+    }
+
+    /// Returns the specification with its precision replaced.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Returns the specification with its [`Truncation`] strategy replaced.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let spec: FormatSpec = "<15".parse().unwrap();
+    /// let spec = spec.with_truncation(Truncation::ElidePath);
+    /// assert_eq!("/home/…/main.rs", spec.pad("/home/u/padder/src/main.rs"));
+    /// ```
+    pub fn with_truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    /// Pads or truncates the string-like `source` according to the specification.
+    ///
+    /// The content is first limited to `precision` units (keeping the start of it), which
+    /// allocates an intermediate output, and then padded (or truncated using the [`Truncation`]
+    /// strategy) to `width`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    /// use std::ffi::OsStr;
+    /// use std::path::Path;
+    ///
+    /// let spec: FormatSpec = "*^9.4".parse().unwrap();
+    /// assert_eq!("**estu***", spec.pad("estus flask"));
+    ///
+    /// let spec: FormatSpec = ".>10".parse().unwrap();
+    /// assert_eq!(OsStr::new("...lordran"), spec.pad(Path::new("lordran")));
+    /// ```
+    pub fn pad<S>(&self, source: S) -> S::Output
+    where
+        S: TruncatingSource,
+        S::Output: TruncatingSource<Output = S::Output>,
+    {
+        match self.precision {
+            Some(precision) => source
+                .pad(Width::AtMost(precision), Alignment::Left, self.fill)
+                .pad_truncating(self.width, self.mode, self.fill, self.truncation),
+            None => source.pad_truncating(self.width, self.mode, self.fill, self.truncation),
+        }
+    }
+
+    /// Pads or truncates the string-like `source` according to the specification by writing
+    /// into the provided `buffer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let mut buf = String::from("|");
+    /// let spec: FormatSpec = "0>6".parse().unwrap();
+    /// spec.pad_to_buffer("451", &mut buf);
+    /// assert_eq!("|000451", buf);
+    /// ```
+    pub fn pad_to_buffer<S>(&self, source: S, buffer: &mut S::Buffer)
+    where
+        S: TruncatingSource,
+        S::Output: TruncatingSource<Output = S::Output, Buffer = S::Buffer>,
+    {
+        match self.precision {
+            Some(precision) => source
+                .pad(Width::AtMost(precision), Alignment::Left, self.fill)
+                .pad_to_buffer_truncating(
+                    self.width,
+                    self.mode,
+                    self.fill,
+                    self.truncation,
+                    buffer,
+                ),
+            None => source.pad_to_buffer_truncating(
+                self.width,
+                self.mode,
+                self.fill,
+                self.truncation,
+                buffer,
+            ),
+        }
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = ParseSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseSpecError::Empty);
+        }
+
+        let as_mode = |c: char| match c {
+            '<' => Some(Alignment::Left),
+            '^' => Some(Alignment::Center),
+            '>' => Some(Alignment::Right),
+            _ => None,
+        };
+
+        let mut chars = s.chars();
+        let first: Option<char> = chars.next();
+        let second: Option<char> = chars.next();
+
+        let (fill, mode, rest) = match (first, second.and_then(as_mode)) {
+            (Some(fill), Some(mode)) => (fill, mode, &s[fill.len_utf8() + 1..]),
+            _ => match first.and_then(as_mode) {
+                Some(mode) => (' ', mode, &s[1..]),
+                None => (' ', Alignment::Left, s),
+            },
+        };
+
+        let (width, precision) = match rest.split_once('.') {
+            Some((width, precision)) => (width, Some(precision)),
+            None => (rest, None),
+        };
+
+        if width.is_empty() {
+            return Err(ParseSpecError::MissingWidth);
+        }
+        let width: usize = match width.parse() {
+            Ok(0) | Err(_) => return Err(ParseSpecError::InvalidWidth(width.to_string())),
+            Ok(width) => width,
+        };
+
+        let precision: Option<usize> = match precision {
+            Some(precision) => Some(
+                precision
+                    .parse()
+                    .map_err(|_| ParseSpecError::InvalidPrecision(precision.to_string()))?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            fill,
+            mode,
+            width,
+            precision,
+            truncation: Truncation::default(),
+        })
+    }
+}
+
+impl fmt::Display for FormatSpec {
+    /// Formats the specification such that it can be parsed back with [`FromStr`], the fill
+    /// symbol is omitted if it is `' '`. The [`Truncation`] strategy is not formatted. The logical [`Alignment::Start`] and [`Alignment::End`]
+    /// are formatted as their left-to-right equivalents `<` and `>`, so they do not round-trip.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.fill != ' ' {
+            write!(f, "{}", self.fill)?;
+        }
+
+        let align: char = match self.mode {
+            Alignment::Left | Alignment::Start => '<',
+            Alignment::Center => '^',
+            Alignment::Right | Alignment::End => '>',
+        };
+        write!(f, "{align}{}", self.width)?;
+
+        if let Some(precision) = self.precision {
+            write!(f, ".{precision}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The errors that can occur when parsing a [`FormatSpec`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseSpecError {
+    /// The specification was empty.
+    Empty,
+    /// The specification did not contain a width.
+    MissingWidth,
+    /// The width was not a positive integer.
+    InvalidWidth(String),
+    /// The precision was not a non-negative integer.
+    InvalidPrecision(String),
+}
+
+impl fmt::Display for ParseSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(
+                f,
+                "empty format spec, expected `[[fill]align][width]['.' precision]`"
+            ),
+            Self::MissingWidth => write!(f, "missing width in format spec"),
+            Self::InvalidWidth(width) => {
+                write!(
+                    f,
+                    "invalid width '{width}' in format spec, expected a positive integer"
+                )
+            }
+            Self::InvalidPrecision(precision) => write!(
+                f,
+                "invalid precision '{precision}' in format spec, expected a non-negative integer"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseSpecError {}
+
+/// The serde representation of a [`FormatSpec`], either its string form, or a map of its string
+/// form and a [`Truncation`] strategy.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum SpecRepr {
+    Spec(String),
+    Full {
+        spec: String,
+        #[serde(default)]
+        truncation: Truncation,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FormatSpec {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.truncation {
+            Truncation::Cut => serializer.collect_str(self),
+            truncation => SpecRepr::Full {
+                spec: self.to_string(),
+                truncation,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FormatSpec {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (spec, truncation): (String, Truncation) = match SpecRepr::deserialize(deserializer)? {
+            SpecRepr::Spec(spec) => (spec, Truncation::default()),
+            SpecRepr::Full { spec, truncation } => (spec, truncation),
+        };
+        let spec: FormatSpec = spec.parse().map_err(serde::de::Error::custom)?;
+        Ok(spec.with_truncation(truncation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::{OsStr, OsString};
+
+    #[test]
+    fn parse_fill_align_width() {
+        let spec: FormatSpec = "*^20".parse().unwrap();
+        assert_eq!(FormatSpec::new('*', Alignment::Center, 20).unwrap(), spec);
+    }
+
+    #[test]
+    fn parse_align_width_precision() {
+        let spec: FormatSpec = "<8.5".parse().unwrap();
+        assert_eq!(
+            FormatSpec::new(' ', Alignment::Left, 8)
+                .unwrap()
+                .with_precision(5),
+            spec
+        );
+    }
+
+    #[test]
+    fn parse_width_only() {
+        let spec: FormatSpec = "12".parse().unwrap();
+        assert_eq!(FormatSpec::new(' ', Alignment::Left, 12).unwrap(), spec);
+    }
+
+    #[test]
+    fn parse_multibyte_fill() {
+        let spec: FormatSpec = "🐉>3".parse().unwrap();
+        assert_eq!(FormatSpec::new('🐉', Alignment::Right, 3).unwrap(), spec);
+    }
+
+    #[test]
+    fn parse_align_as_fill() {
+        let spec: FormatSpec = "<>4".parse().unwrap();
+        assert_eq!(FormatSpec::new('<', Alignment::Right, 4).unwrap(), spec);
+    }
+
+    #[test]
+    fn display_start_end_is_lossy() {
+        let spec = FormatSpec::new('-', Alignment::Start, 6).unwrap();
+        assert_eq!("-<6", spec.to_string());
+        assert_eq!(
+            FormatSpec::new('-', Alignment::Left, 6).unwrap(),
+            spec.to_string().parse().unwrap()
+        );
+
+        let spec = FormatSpec::new(' ', Alignment::End, 3).unwrap();
+        assert_eq!(">3", spec.to_string());
+        assert_eq!(
+            FormatSpec::new(' ', Alignment::Right, 3).unwrap(),
+            spec.to_string().parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Err(ParseSpecError::Empty), "".parse::<FormatSpec>());
+        assert_eq!(
+            Err(ParseSpecError::MissingWidth),
+            "*^".parse::<FormatSpec>()
+        );
+        assert_eq!(
+            Err(ParseSpecError::MissingWidth),
+            ">.5".parse::<FormatSpec>()
+        );
+        assert_eq!(
+            Err(ParseSpecError::InvalidWidth(String::from("x12"))),
+            "x12".parse::<FormatSpec>()
+        );
+        assert_eq!(
+            Err(ParseSpecError::InvalidWidth(String::from("0"))),
+            ">0".parse::<FormatSpec>()
+        );
+        assert_eq!(
+            Err(ParseSpecError::InvalidPrecision(String::from("-1"))),
+            "^3.-1".parse::<FormatSpec>()
+        );
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        for s in ["*^20", "<8.5", ">1", "🐉<3.0"] {
+            let spec: FormatSpec = s.parse().unwrap();
+            assert_eq!(s, spec.to_string());
+            assert_eq!(spec, spec.to_string().parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn pad_precision() {
+        let spec: FormatSpec = "-^9.4".parse().unwrap();
+        assert_eq!("--estu---", spec.pad("estus flask"));
+        assert_eq!("----ツ----", spec.pad("ツ"));
+    }
+
+    #[test]
+    fn pad_truncates() {
+        let spec: FormatSpec = ">4".parse().unwrap();
+        assert_eq!("ring", spec.pad("elden ring"));
+    }
+
+    #[test]
+    fn pad_string_sources() {
+        let spec: FormatSpec = "0>5".parse().unwrap();
+        assert_eq!("00ash", spec.pad(String::from("ash")));
+
+        let mut buffer = OsString::from("|");
+        spec.with_precision(1)
+            .pad_to_buffer(OsStr::new("ash"), &mut buffer);
+        assert_eq!(OsStr::new("|0000a"), buffer);
+    }
+
+    #[test]
+    fn pad_truncation() {
+        let spec = FormatSpec::new(' ', Alignment::Right, 13)
+            .unwrap()
+            .with_truncation(Truncation::ElidePath);
+        assert_eq!(
+            "…/lordran.sav",
+            spec.with_precision(30).pad("/mnt/saves/lordran.sav")
+        );
+        assert_eq!("…/lordran.sav", spec.pad("/mnt/saves/lordran.sav"));
+
+        let mut buffer = String::new();
+        spec.pad_to_buffer("/mnt/saves/lordran.sav", &mut buffer);
+        assert_eq!("…/lordran.sav", buffer);
+        assert_eq!(">13", spec.to_string());
+    }
+
+    #[test]
+    fn pad_to_buffer() {
+        let spec: FormatSpec = "§<6.3".parse().unwrap();
+        let mut buffer = String::new();
+        spec.pad_to_buffer("yharnam", &mut buffer);
+        spec.pad_to_buffer("ya", &mut buffer);
+        assert_eq!("yha§§§ya§§§§", buffer);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_serde {
+    use super::*;
+
+    #[test]
+    fn spec_roundtrip() {
+        let specs: Vec<FormatSpec> = serde_json::from_str(r#"["*^20", "<8.5"]"#).unwrap();
+        assert_eq!(
+            FormatSpec::new('*', Alignment::Center, 20).unwrap(),
+            specs[0]
+        );
+        assert_eq!(r#"["*^20","<8.5"]"#, serde_json::to_string(&specs).unwrap());
+    }
+
+    #[test]
+    fn spec_truncation_roundtrip() {
+        let spec: FormatSpec = serde_json::from_str(r#"{ "spec": "<8" }"#).unwrap();
+        assert_eq!(Truncation::Cut, spec.truncation());
+        assert_eq!(r#""<8""#, serde_json::to_string(&spec).unwrap());
+
+        let spec = spec.with_truncation(Truncation::ElidePath);
+        let json: String = serde_json::to_string(&spec).unwrap();
+        assert_eq!(r#"{"spec":"<8","truncation":"elide_path"}"#, json);
+        assert_eq!(spec, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn spec_invalid() {
+        let err = serde_json::from_str::<FormatSpec>(r#""*^""#).unwrap_err();
+        assert!(err.to_string().contains("missing width"));
+    }
+}
@@ -0,0 +1,358 @@
+use std::borrow::Cow;
+
+use crate::alignment::Alignment;
+use crate::metric::{CharWidth, MetricSource, WidthMetric};
+
+/// Describes how delimited text (CSV/TSV) is formatted into an aligned plain-text table.
+///
+/// The width of every column is computed from its widest cell, as measured by the `metric`
+/// (utf8 chars by default, e.g., [`crate::ColumnWidth`] for terminals with the `unicode-width`
+/// feature), optionally capped at `max_width`, in which case wider cells are truncated and end
+/// with the `marker` (or are truncated keeping their start if the `marker` does not fit), while
+/// numeric cells always overflow the column rather than losing digits.
+/// Columns where every cell (except the header) is a number are aligned to the right, including
+/// their header, all other columns to the left. Each cell is padded using
+/// [`MetricSource::pad_to_buffer_with_metric`], except at the end of the last column, so that
+/// lines carry no trailing spaces.
+///
+/// Fields may be quoted with `"`, in which case they can contain the delimiter and escaped
+/// `""` quotes, but not line breaks.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let csv = "boss,souls,location\nGwyn,70000,Kiln of the First Flame\nOrnstein,20000,\"Anor Londo, Cathedral\"\n";
+/// let table = TableFormat::new(',').with_max_width(12).format(csv);
+/// assert_eq!(
+///     "boss      souls  location\n\
+///      Gwyn      70000  Kiln of the…\n\
+///      Ornstein  20000  Anor Londo,…\n",
+///     table,
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableFormat<M: WidthMetric = CharWidth> {
+    pub delimiter: char,
+    pub separator: String,
+    pub max_width: Option<usize>,
+    pub marker: String,
+    pub metric: M,
+}
+
+impl Default for TableFormat {
+    /// Comma delimited input, columns separated by two spaces, no maximum column width, and
+    /// widths measured in utf8 chars.
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            separator: String::from("  "),
+            max_width: None,
+            marker: String::from("…"),
+            metric: CharWidth,
+        }
+    }
+}
+
+impl TableFormat {
+    /// Creates a new table format for input delimited by the `delimiter`, e.g., `','` or `'\t'`.
+    pub fn new(delimiter: char) -> Self {
+        Self {
+            delimiter,
+            ..Self::default()
+        }
+    }
+}
+
+impl<M: WidthMetric> TableFormat<M> {
+    /// Returns the format with the widths of cells measured by the `metric`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let table = TableFormat::new(',').with_metric(ByteWidth).format("ツ,x\nab,y\n");
+    /// assert_eq!("ツ  x\nab   y\n", table);
+    /// ```
+    pub fn with_metric<N: WidthMetric>(self, metric: N) -> TableFormat<N> {
+        TableFormat {
+            delimiter: self.delimiter,
+            separator: self.separator,
+            max_width: self.max_width,
+            marker: self.marker,
+            metric,
+        }
+    }
+
+    /// Returns the format with the separator between columns replaced.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Returns the format with every column capped at `max_width` units of the metric.
+    ///
+    /// # Panics
+    /// If the `max_width` is 0.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        assert!(max_width > 0, "the maximum column width must be at least 1");
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Returns the format with the marker ending truncated cells replaced.
+    pub fn with_marker(mut self, marker: impl Into<String>) -> Self {
+        self.marker = marker.into();
+        self
+    }
+
+    /// Formats the delimited `input` into an aligned table.
+    pub fn format(&self, input: &str) -> String {
+        let mut buffer = String::with_capacity(input.len());
+        self.format_to_buffer(input, &mut buffer);
+        buffer
+    }
+
+    /// Formats the delimited `input` into an aligned table, appending it to the `buffer`.
+    ///
+    /// Empty lines are skipped, and rows with fewer cells than the widest row are filled with
+    /// empty cells. A `max_width` of 0 is treated as 1.
+    pub fn format_to_buffer(&self, input: &str, buffer: &mut String) {
+        let rows: Vec<Vec<Cow<'_, str>>> = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| split_fields(line, self.delimiter))
+            .collect();
+
+        let n_cols: usize = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut widths: Vec<usize> = vec![0; n_cols];
+        let mut numeric: Vec<bool> = vec![true; n_cols];
+        for (row_idx, row) in rows.iter().enumerate() {
+            for (col_idx, cell) in row.iter().enumerate() {
+                widths[col_idx] = widths[col_idx].max(self.metric.measure(cell));
+                if row_idx > 0 && !cell.is_empty() && !is_number(cell) {
+                    numeric[col_idx] = false;
+                }
+            }
+        }
+
+        let modes: Vec<Alignment> = numeric
+            .iter()
+            .map(|&is_numeric| match is_numeric && rows.len() > 1 {
+                true => Alignment::Right,
+                false => Alignment::Left,
+            })
+            .collect();
+        if let Some(max_width) = self.max_width {
+            widths
+                .iter_mut()
+                .for_each(|width| *width = (*width).min(max_width.max(1)));
+        }
+
+        for row in &rows {
+            for col_idx in 0..n_cols {
+                if col_idx > 0 {
+                    buffer.push_str(&self.separator);
+                }
+                let cell: &str = row.get(col_idx).map_or("", |cell| cell.as_ref());
+                let is_last: bool = col_idx + 1 == n_cols;
+                self.write_cell(cell, widths[col_idx], modes[col_idx], is_last, buffer);
+            }
+            buffer.push('\n');
+        }
+    }
+
+    /// Pads the `cell` into the `buffer`, truncating it and appending the marker if it is wider
+    /// than the `width`.
+    ///
+    /// Right-aligned (numeric) cells overflow the `width` instead of being truncated, and other
+    /// cells are truncated keeping their start if the marker does not fit. Cells of the last
+    /// column (`is_last`) are not padded at their end, and empty ones are not padded at all.
+    fn write_cell(
+        &self,
+        cell: &str,
+        width: usize,
+        mode: Alignment,
+        is_last: bool,
+        buffer: &mut String,
+    ) {
+        let n_units_cell: usize = self.metric.measure(cell);
+        if n_units_cell <= width {
+            match is_last && (mode == Alignment::Left || cell.is_empty()) {
+                true => buffer.push_str(cell),
+                false => cell.pad_to_buffer_with_metric(width, mode, ' ', &self.metric, buffer),
+            }
+            return;
+        }
+
+        if mode == Alignment::Right {
+            buffer.push_str(cell);
+            return;
+        }
+
+        let n_units_marker: usize = self.metric.measure(&self.marker);
+        if n_units_marker < width {
+            let content: &str = cell.truncate_to_fit_with_metric(
+                width - n_units_marker,
+                Alignment::Left,
+                &self.metric,
+            );
+            buffer.push_str(content);
+            buffer.push_str(&self.marker);
+            if !is_last {
+                let n_spaces: usize = width - n_units_marker - self.metric.measure(content);
+                buffer.extend(std::iter::repeat_n(' ', n_spaces));
+            }
+            return;
+        }
+
+        match is_last {
+            true => buffer.push_str(cell.truncate_to_fit_with_metric(
+                width,
+                Alignment::Left,
+                &self.metric,
+            )),
+            false => {
+                cell.pad_to_buffer_with_metric(width, Alignment::Left, ' ', &self.metric, buffer)
+            }
+        }
+    }
+}
+
+/// Returns whether the `cell` is a (possibly signed or decimal) number.
+fn is_number(cell: &str) -> bool {
+    let cell: &str = cell.trim();
+    cell.parse::<i128>().is_ok()
+        || (cell.parse::<f64>().is_ok() && cell.chars().any(|c| c.is_ascii_digit()))
+}
+
+/// Splits the `line` on the `delimiter`, unquoting fields which are enclosed in `"`.
+fn split_fields(line: &str, delimiter: char) -> Vec<Cow<'_, str>> {
+    let mut fields: Vec<Cow<'_, str>> = Vec::new();
+    let mut rest: &str = line;
+    loop {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let mut field = String::new();
+            let mut chars = quoted.char_indices();
+            let mut ed_byte: usize = quoted.len();
+            while let Some((byte_offset, c)) = chars.next() {
+                if c != '"' {
+                    field.push(c);
+                    continue;
+                }
+                if quoted[byte_offset + 1..].starts_with('"') {
+                    field.push('"');
+                    chars.next();
+                    continue;
+                }
+                ed_byte = byte_offset + 1;
+                break;
+            }
+            fields.push(Cow::Owned(field));
+            rest = &quoted[ed_byte..];
+            match rest.find(delimiter) {
+                Some(byte_offset) => rest = &rest[byte_offset + delimiter.len_utf8()..],
+                None => return fields,
+            }
+        } else {
+            match rest.find(delimiter) {
+                Some(byte_offset) => {
+                    fields.push(Cow::Borrowed(&rest[..byte_offset]));
+                    rest = &rest[byte_offset + delimiter.len_utf8()..];
+                }
+                None => {
+                    fields.push(Cow::Borrowed(rest));
+                    return fields;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_quoted() {
+        assert_eq!(
+            vec!["a", "b,c", "say \"hi\"", ""],
+            split_fields(r#"a,"b,c","say ""hi""","#, ',')
+        );
+        assert_eq!(vec!["ツ", "", "x"], split_fields("ツ\t\tx", '\t'));
+    }
+
+    #[test]
+    fn tsv_infers_alignment() {
+        let tsv = "name\tlevel\tratio\nsolaire\t12\t-0.5\nlautrec\t7\t1e3\n";
+        assert_eq!(
+            "name     level  ratio\n\
+             solaire     12   -0.5\n\
+             lautrec      7    1e3\n",
+            TableFormat::new('\t').format(tsv)
+        );
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let csv = "a,b,c\n1\n\n22,333\n";
+        assert_eq!(
+            " a|  b|c\n 1|   |\n22|333|\n",
+            TableFormat::default().with_separator("|").format(csv)
+        );
+    }
+
+    #[test]
+    fn narrow_max_width_truncates_without_marker() {
+        let csv = "こんにちは,x\n";
+        assert_eq!(
+            "こ x\n",
+            TableFormat::default()
+                .with_max_width(1)
+                .with_separator(" ")
+                .format(csv)
+        );
+    }
+
+    #[test]
+    fn narrow_max_width_overflows_numbers() {
+        let csv = "n,s\n123,abc\n";
+        assert_eq!(
+            "n s\n123 a\n",
+            TableFormat::default()
+                .with_max_width(1)
+                .with_separator(" ")
+                .format(csv)
+        );
+    }
+
+    #[test]
+    fn max_width_with_marker_overflows_numbers() {
+        let csv = "souls,boss\n12345,Gwyn Lord of Cinder\n7,Manus\n";
+        assert_eq!(
+            "souls  boss\n\
+             12345  Gwyn …\n\
+             \x20   7  Manus\n",
+            TableFormat::default().with_max_width(6).format(csv)
+        );
+        assert_eq!(
+            "  n  s\n12345  abc\n",
+            TableFormat::default()
+                .with_max_width(3)
+                .format("n,s\n12345,abc\n")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_max_width() {
+        TableFormat::default().with_max_width(0);
+    }
+
+    #[test]
+    fn zero_max_width_field() {
+        let mut format = TableFormat::default().with_separator(" ");
+        format.max_width = Some(0);
+        assert_eq!("こ x\n", format.format("こんにちは,x\n"));
+    }
+}
@@ -0,0 +1,286 @@
+use std::borrow::Cow;
+
+use crate::alignment::Alignment;
+use crate::mutable_source::MutableSource;
+use crate::path::elide_path;
+use crate::source::Source;
+use crate::width::Width;
+
+/// Specifies the strategy used to truncate string content which is wider than the target width.
+///
+/// - `Cut`: removes excess chars according to the alignment mode, see [`Source::truncate_to_fit`].
+/// - `ElidePath`: treats the content as a file path and elides whole components from the
+///   middle of it (`/home/u/…/src/main.rs`), always keeping the file name. The alignment mode
+///   decides whether leading (left), trailing (right), or both (center) components are kept.
+///   Chars are elided from the middle of the file name only when the file name alone exceeds
+///   the width.
+///
+/// Strategies are applied through [`TruncatingSource`] and [`MutableTruncatingSource`].
+///
+/// With the `serde` feature enabled a strategy is (de)serialized as `"cut"` or `"elide_path"`.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let path: &str = "/home/u/projects/padder/src/main.rs";
+/// assert_eq!("/home/u/…/src/main.rs", Truncation::ElidePath.truncate(path, 21, Alignment::Left));
+/// assert_eq!("/…/padder/src/main.rs", Truncation::ElidePath.truncate(path, 21, Alignment::Right));
+/// assert_eq!("main.rs", Truncation::Cut.truncate(path, 7, Alignment::Right));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Truncation {
+    #[default]
+    Cut,
+    ElidePath,
+}
+
+impl Truncation {
+    /// Truncates the `s` to the specified `width` (in utf8 chars) according to the strategy,
+    /// borrowing from `s` whenever possible.
+    ///
+    /// The alignment `mode` decides which chars are removed when cutting, and which path
+    /// components are kept when eliding.
+    pub fn truncate<'a>(&self, s: &'a str, width: usize, mode: Alignment) -> Cow<'a, str> {
+        if s.chars().count() <= width {
+            return Cow::Borrowed(s);
+        }
+
+        match self {
+            Self::Cut => Cow::Borrowed(s.truncate_to_fit(width, mode)),
+            Self::ElidePath => elide_path(s, width, mode),
+        }
+    }
+}
+
+/// A companion trait to [`Source`] for string types (and OS strings and paths) which pads like
+/// [`Source::pad`], but truncates content wider than `width` using a [`Truncation`] strategy.
+///
+/// Strategies which elide content may end up narrower than `width`, the truncated content is
+/// then padded to `width` using the `symbol`.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let paths = ["src/lib.rs", "/home/u/projects/padder/src/truncation.rs"];
+/// let mut buffer = String::new();
+/// for path in paths {
+///     path.pad_to_buffer_truncating(24, Alignment::Left, ' ', Truncation::ElidePath, &mut buffer);
+///     buffer.push('|');
+/// }
+/// assert_eq!("src/lib.rs              |/home/u/…/truncation.rs |", buffer);
+/// ```
+pub trait TruncatingSource: Source<Symbol = char> {
+    /// Pads (or truncates using the `truncation` strategy) to the specified `width` using the
+    /// given `symbol` according to the specified alignment `mode`.
+    fn pad_truncating(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+    ) -> Self::Output;
+
+    /// Pads (or truncates using the `truncation` strategy) to the specified `width` using the
+    /// given `symbol` according to the specified alignment `mode`, appending the result to the
+    /// `buffer`.
+    fn pad_to_buffer_truncating(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+        buffer: &mut Self::Buffer,
+    );
+}
+
+impl TruncatingSource for &str {
+    fn pad_truncating(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+    ) -> String {
+        let mut output = String::with_capacity(self.len());
+        pad_str_to_buffer_truncating(self, width, mode, symbol, truncation, &mut output);
+        output
+    }
+
+    fn pad_to_buffer_truncating(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+        buffer: &mut String,
+    ) {
+        pad_str_to_buffer_truncating(self, width, mode, symbol, truncation, buffer);
+    }
+}
+
+impl TruncatingSource for String {
+    fn pad_truncating(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+    ) -> String {
+        self.as_str()
+            .pad_truncating(width, mode, symbol, truncation)
+    }
+
+    fn pad_to_buffer_truncating(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+        buffer: &mut String,
+    ) {
+        pad_str_to_buffer_truncating(self, width, mode, symbol, truncation, buffer);
+    }
+}
+
+/// A companion trait to [`MutableSource`] which pads a string in-place, truncating content
+/// wider than `width` using a [`Truncation`] strategy, see [`TruncatingSource`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut s = String::from("/mnt/data/saves/lordran/chosen_undead.sav");
+/// (&mut s).pad_truncating(24, Alignment::Right, ' ', Truncation::ElidePath);
+/// assert_eq!("/mnt/…/chosen_undead.sav", s);
+/// ```
+pub trait MutableTruncatingSource: MutableSource<Symbol = char> {
+    /// Pads (or truncates using the `truncation` strategy) the string in-place to the specified
+    /// `width` using the given `symbol` according to the specified alignment `mode`.
+    ///
+    /// Cutting is performed in-place, other strategies replace the content of the string while
+    /// keeping its capacity.
+    fn pad_truncating(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+    );
+}
+
+impl MutableTruncatingSource for &mut String {
+    fn pad_truncating(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        truncation: Truncation,
+    ) {
+        pad_string_truncating(self, width, mode, symbol, truncation);
+    }
+}
+
+/// Pads the `s` into the `buffer`, truncating it according to the `truncation` strategy.
+fn pad_str_to_buffer_truncating(
+    s: &str,
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: char,
+    truncation: Truncation,
+    buffer: &mut String,
+) {
+    let n_chars_original: usize = s.chars().count();
+    let width: usize = width.into().resolve(n_chars_original);
+    if width < n_chars_original {
+        let truncated: Cow<'_, str> = truncation.truncate(s, width, mode);
+        truncated
+            .as_ref()
+            .pad_to_buffer(width, mode, symbol, buffer);
+        return;
+    }
+    s.pad_to_buffer(width, mode, symbol, buffer);
+}
+
+/// Pads the `s` in-place, truncating it according to the `truncation` strategy.
+///
+/// Cutting is performed in-place, other strategies replace the content of the string while
+/// keeping its capacity.
+fn pad_string_truncating(
+    mut s: &mut String,
+    width: impl Into<Width>,
+    mode: Alignment,
+    symbol: char,
+    truncation: Truncation,
+) {
+    let n_chars_original: usize = s.chars().count();
+    let width: usize = width.into().resolve(n_chars_original);
+    if width < n_chars_original && truncation != Truncation::Cut {
+        let truncated: String = truncation.truncate(s, width, mode).into_owned();
+        s.clear();
+        s.push_str(&truncated);
+    }
+    MutableSource::pad(&mut s, width, mode, symbol);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_pad_truncating_cut() {
+        let s: &str = "elden ring";
+        assert_eq!(
+            s.pad(4, Alignment::Center, ' '),
+            s.pad_truncating(4, Alignment::Center, ' ', Truncation::Cut)
+        );
+    }
+
+    #[test]
+    fn str_pad_truncating_pads() {
+        let s: &str = "src/main.rs";
+        assert_eq!(
+            "~~src/main.rs",
+            s.pad_truncating(13, Alignment::Right, '~', Truncation::ElidePath)
+        );
+    }
+
+    #[test]
+    fn string_pad_truncating_elide_path() {
+        let s = String::from("C:/Users/ashen/AppData/DarkSoulsIII/DS30000.sl2");
+        assert_eq!(
+            "C:/…/DarkSoulsIII/DS30000.sl2",
+            s.pad_truncating(29, Alignment::Right, ' ', Truncation::ElidePath)
+        );
+    }
+
+    #[test]
+    fn string_pad_to_buffer_truncating_width() {
+        let s = String::from("/var/log/firelink/shrine.log");
+        let mut buffer = String::from(">");
+        s.pad_to_buffer_truncating(
+            Width::AtMost(12),
+            Alignment::Left,
+            ' ',
+            Truncation::ElidePath,
+            &mut buffer,
+        );
+        assert_eq!(">…/shrine.log", buffer);
+    }
+
+    #[test]
+    fn mut_string_pad_truncating() {
+        let mut s = String::from("/home/u/a_really_long_file_name.txt");
+        let capacity: usize = s.capacity();
+        (&mut s).pad_truncating(9, Alignment::Left, ' ', Truncation::ElidePath);
+        assert_eq!("a_re….txt", s);
+        assert_eq!(capacity, s.capacity());
+
+        let mut s = String::from("/home/u/x.txt");
+        (&mut s).pad_truncating(5, Alignment::Right, ' ', Truncation::Cut);
+        assert_eq!("x.txt", s);
+    }
+}
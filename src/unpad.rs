@@ -0,0 +1,281 @@
+use crate::alignment::Alignment;
+use crate::mutable_source::MutableSource;
+use crate::source::Source;
+
+/// A companion trait to [`Source`] which provides the inverse operation of [`Source::pad`].
+///
+/// Given the alignment `mode` and the `symbol` that was used when padding, the padding
+/// symbols are trimmed from the side(s) where they would have been inserted:
+/// - [`Alignment::Left`]: trims symbols from the right.
+/// - [`Alignment::Right`]: trims symbols from the left.
+/// - [`Alignment::Center`]: trims symbols from both ends.
+///
+/// The result is a borrowed view into the original buffer, no allocations are performed.
+///
+/// Note that content which itself starts or ends with the padding symbol can not be
+/// distinguished from the padding, and will also be trimmed.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let padded = "firelink".pad(12, Alignment::Center, '~');
+/// assert_eq!("~~firelink~~", padded);
+/// assert_eq!("firelink", padded.unpad(Alignment::Center, '~'));
+/// ```
+pub trait Unpad: Source {
+    /// Trims the padding `symbol`s from the buffer according to the specified alignment `mode`.
+    fn unpad<'a>(&'a self, mode: Alignment, symbol: Self::Symbol) -> Self::Slice<'a>;
+}
+
+/// A companion trait to [`MutableSource`] which provides the inverse operation of
+/// [`MutableSource::pad`], trimming the padding symbols in-place.
+///
+/// See [`Unpad`] for how the padding symbols are trimmed for each alignment mode.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut s = String::from("lothric");
+/// (&mut s).pad(10, Alignment::Right, '🔥');
+/// assert_eq!("🔥🔥🔥lothric", s);
+///
+/// (&mut s).unpad(Alignment::Right, '🔥');
+/// assert_eq!("lothric", s);
+/// ```
+pub trait MutableUnpad: MutableSource {
+    /// Trims the padding `symbol`s from the buffer in-place according to the specified
+    /// alignment `mode`.
+    fn unpad(&mut self, mode: Alignment, symbol: Self::Symbol);
+}
+
+/// Returns the `(start, end)` indices of the items in `slice` which are left after trimming
+/// the `symbol` according to the alignment `mode`.
+fn unpadded_range<T: PartialEq>(slice: &[T], mode: Alignment, symbol: &T) -> (usize, usize) {
+    let st_idx: usize = match mode {
+        Alignment::Left | Alignment::Start => 0,
+        Alignment::Right | Alignment::End | Alignment::Center => slice
+            .iter()
+            .position(|item| item != symbol)
+            .unwrap_or(slice.len()),
+    };
+
+    let ed_idx: usize = match mode {
+        Alignment::Right | Alignment::End => slice.len(),
+        Alignment::Left | Alignment::Start | Alignment::Center => slice[st_idx..]
+            .iter()
+            .rposition(|item| item != symbol)
+            .map_or(st_idx, |idx| st_idx + idx + 1),
+    };
+
+    (st_idx, ed_idx)
+}
+
+/// Trims the `symbol` from the `s` according to the alignment `mode`.
+fn unpad_str(s: &str, mode: Alignment, symbol: char) -> &str {
+    match mode {
+        Alignment::Left | Alignment::Start => s.trim_end_matches(symbol),
+        Alignment::Right | Alignment::End => s.trim_start_matches(symbol),
+        Alignment::Center => s.trim_matches(symbol),
+    }
+}
+
+impl Unpad for &str {
+    /// Trims the padding `symbol`s from the &str according to the specified alignment `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let s: &str = "0000451";
+    /// assert_eq!("451", s.unpad(Alignment::Right, '0'));
+    /// ```
+    fn unpad<'a>(&'a self, mode: Alignment, symbol: Self::Symbol) -> Self::Slice<'a> {
+        unpad_str(self, mode, symbol)
+    }
+}
+
+impl Unpad for String {
+    /// Trims the padding `symbol`s from the string according to the specified alignment `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let s = String::from("ashen one...");
+    /// assert_eq!("ashen one", s.unpad(Alignment::Left, '.'));
+    /// ```
+    fn unpad<'a>(&'a self, mode: Alignment, symbol: Self::Symbol) -> Self::Slice<'a> {
+        unpad_str(self, mode, symbol)
+    }
+}
+
+impl<T> Unpad for Vec<T>
+where
+    T: Clone + Copy + Sized + PartialEq,
+{
+    /// Trims the padding `symbol`s from the vector according to the specified alignment `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let v: Vec<u8> = Vec::from(&[0u8, 0, 4, 2, 0]);
+    /// assert_eq!(&[4u8, 2], v.unpad(Alignment::Center, 0u8));
+    /// ```
+    fn unpad<'a>(&'a self, mode: Alignment, symbol: Self::Symbol) -> Self::Slice<'a> {
+        let (st_idx, ed_idx) = unpadded_range(self, mode, &symbol);
+        &self[st_idx..ed_idx]
+    }
+}
+
+impl<T> Unpad for &[T]
+where
+    T: Clone + Copy + Sized + PartialEq,
+{
+    /// Trims the padding `symbol`s from the slice according to the specified alignment `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use padder::*;
+    ///
+    /// let s: &[char] = &['y', 'o', 'r', 'n', 'o'];
+    /// assert_eq!(&['y', 'o', 'r', 'n'], s.unpad(Alignment::Left, 'o'));
+    /// ```
+    fn unpad<'a>(&'a self, mode: Alignment, symbol: Self::Symbol) -> Self::Slice<'a> {
+        let (st_idx, ed_idx) = unpadded_range(self, mode, &symbol);
+        &self[st_idx..ed_idx]
+    }
+}
+
+impl MutableUnpad for &mut String {
+    /// Trims the padding `symbol`s from the string in-place according to the specified
+    /// alignment `mode`.
+    ///
+    /// No allocations are performed, the capacity of the string is left unchanged.
+    fn unpad(&mut self, mode: Alignment, symbol: Self::Symbol) {
+        if let Alignment::Left | Alignment::Start | Alignment::Center = mode {
+            let ed_byte: usize = self.trim_end_matches(symbol).len();
+            self.truncate(ed_byte);
+        }
+
+        if let Alignment::Right | Alignment::End | Alignment::Center = mode {
+            let st_byte: usize = self.len() - self.trim_start_matches(symbol).len();
+            self.replace_range(..st_byte, "");
+        }
+    }
+}
+
+impl<T> MutableUnpad for &mut Vec<T>
+where
+    T: Copy + Sized + PartialEq,
+{
+    /// Trims the padding `symbol`s from the vector in-place according to the specified
+    /// alignment `mode`.
+    ///
+    /// No allocations are performed, the capacity of the vector is left unchanged.
+    fn unpad(&mut self, mode: Alignment, symbol: Self::Symbol) {
+        let (st_idx, ed_idx) = unpadded_range(self, mode, &symbol);
+        self.truncate(ed_idx);
+        self.drain(..st_idx);
+    }
+}
+
+#[cfg(test)]
+mod tests_str {
+    use super::*;
+
+    #[test]
+    fn unpad_left() {
+        let source: &str = "Artorias🤠🤠";
+        assert_eq!("Artorias", source.unpad(Alignment::Left, '🤠'));
+    }
+
+    #[test]
+    fn unpad_right() {
+        let source: &str = "22222kebab";
+        assert_eq!("kebab", source.unpad(Alignment::Right, '2'));
+    }
+
+    #[test]
+    fn unpad_center() {
+        let source: &str = "--astro bot!---";
+        assert_eq!("astro bot!", source.unpad(Alignment::Center, '-'));
+    }
+
+    #[test]
+    fn unpad_only_symbols() {
+        let source: &str = "ツツツ";
+        assert_eq!("", source.unpad(Alignment::Center, 'ツ'));
+        assert_eq!("", source.unpad(Alignment::Left, 'ツ'));
+    }
+
+    #[test]
+    fn unpad_roundtrip() {
+        let source: &str = "seKiro";
+        for mode in [Alignment::Left, Alignment::Right, Alignment::Center] {
+            let padded: String = source.pad(15, mode, 'ツ');
+            assert_eq!(source, padded.unpad(mode, 'ツ'));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_slice {
+    use super::*;
+
+    #[test]
+    fn unpad_left() {
+        let source: &[u32] = &[1, 2, 3, 1337, 1337];
+        assert_eq!(&[1u32, 2, 3], source.unpad(Alignment::Left, 1337));
+    }
+
+    #[test]
+    fn unpad_right() {
+        let source: Vec<i32> = Vec::from(&[-1998, -1998, 1, -1998, 3]);
+        assert_eq!(&[1i32, -1998, 3], source.unpad(Alignment::Right, -1998));
+    }
+
+    #[test]
+    fn unpad_center_only_symbols() {
+        let source: Vec<bool> = Vec::from(&[false, false, false]);
+        let empty: &[bool] = &[];
+        assert_eq!(empty, source.unpad(Alignment::Center, false));
+    }
+}
+
+#[cfg(test)]
+mod tests_mutable {
+    use super::*;
+
+    #[test]
+    fn string_unpad_center() {
+        let mut source = String::from("きplantきき");
+        (&mut source).unpad(Alignment::Center, 'き');
+        assert_eq!("plant", source);
+    }
+
+    #[test]
+    fn string_unpad_right() {
+        let mut source = String::from("🚀🚀🚀🚀rocketTT");
+        let capacity: usize = source.capacity();
+        (&mut source).unpad(Alignment::Right, '🚀');
+        assert_eq!("rocketTT", source);
+        assert_eq!(capacity, source.capacity());
+    }
+
+    #[test]
+    fn vec_unpad_left() {
+        let mut source: Vec<char> = Vec::from(&['😺', '2', '¡', '🐛', '🐛']);
+        (&mut source).unpad(Alignment::Left, '🐛');
+        assert_eq!(Vec::from(&['😺', '2', '¡']), source);
+    }
+
+    #[test]
+    fn vec_unpad_center() {
+        let mut source: Vec<u8> = Vec::from(&[9u8, 1, 9, 2, 9, 9]);
+        (&mut source).unpad(Alignment::Center, 9);
+        assert_eq!(Vec::from(&[1u8, 9, 2]), source);
+    }
+}
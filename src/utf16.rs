@@ -0,0 +1,346 @@
+use crate::alignment::Alignment;
+use crate::byte_width::SymbolWidthError;
+use crate::metric::{Utf16Width, pad_metric_in_place, pad_metric_to_sink};
+use crate::mutable_source::MutableSource;
+use crate::source::Source;
+use crate::width::Width;
+
+/// Returns an error if the `symbol` is encoded in more than a single UTF-16 code unit, i.e., if
+/// it is outside of the Basic Multilingual Plane.
+fn check_symbol(symbol: char) -> Result<(), SymbolWidthError> {
+    match symbol.len_utf16() {
+        1 => Ok(()),
+        width => Err(SymbolWidthError { symbol, width }),
+    }
+}
+
+/// Returns true if the `idx` does not split a surrogate pair of the `units`.
+fn is_unit_boundary(units: &[u16], idx: usize) -> bool {
+    if idx == 0 || idx >= units.len() {
+        return true;
+    }
+    !((0xD800..0xDC00).contains(&units[idx - 1]) && (0xDC00..0xE000).contains(&units[idx]))
+}
+
+/// Returns the code unit range of the `units` which is kept when truncating them to at most
+/// `width` code units according to the alignment `mode`, never splitting a surrogate pair.
+fn unit_range(units: &[u16], width: usize, mode: Alignment) -> (usize, usize) {
+    let n_units: usize = units.len();
+    if width >= n_units {
+        return (0, n_units);
+    }
+
+    let is_boundary = |idx: usize| is_unit_boundary(units, idx);
+    let floor = |idx: usize| (0..=idx).rev().find(|&idx| is_boundary(idx)).unwrap_or(0);
+    let st_idx: usize = match mode {
+        Alignment::Left | Alignment::Start => 0,
+        Alignment::Right | Alignment::End => (n_units - width..=n_units)
+            .find(|&idx| is_boundary(idx))
+            .unwrap_or(n_units),
+        // Moves the window to the left rather than losing the unit it starts in.
+        Alignment::Center => floor((n_units - width) / 2),
+    };
+    (st_idx, floor((st_idx + width).min(n_units)))
+}
+
+/// A companion trait to [`Source`] for string types which pads (or truncates) to a width in
+/// UTF-16 code units instead of chars, e.g., for fixed-width fields consumed by JavaScript,
+/// Java or Windows APIs, where astral chars like '🐉' count as 2.
+///
+/// Truncation never splits a surrogate pair, instead the code unit which is left over is filled
+/// with the pad `symbol` (according to the alignment `mode`), such that the output is always
+/// exactly `width` code units long. The pad `symbol` hence has to be a single code unit, i.e.,
+/// inside the Basic Multilingual Plane, otherwise a [`SymbolWidthError`] is returned.
+///
+/// This is [`MetricSource`](crate::MetricSource) with the [`Utf16Width`] metric, restricted to
+/// single code unit symbols such that every width can be filled exactly.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// // '🐉' is encoded in 2 UTF-16 code units.
+/// assert_eq!(Ok(String::from("🐉ash ")), "🐉ash".pad_utf16(6, Alignment::Left, ' '));
+///
+/// // The second '🐉' does not fit, so the leftover code unit is padded.
+/// assert_eq!(Ok(String::from("*🐉")), "🐉🐉".pad_utf16(3, Alignment::Right, '*'));
+///
+/// assert!("ash".pad_utf16(5, Alignment::Left, '🐉').is_err());
+/// ```
+pub trait Utf16WidthSource: Source<Symbol = char> {
+    /// Pads (or truncates) the string to the specified `width` (in UTF-16 code units) using the
+    /// given single code unit `symbol` according to the specified alignment `mode`.
+    fn pad_utf16(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<String, SymbolWidthError>;
+
+    /// Pads (or truncates) the string to the specified `width` (in UTF-16 code units) using the
+    /// given single code unit `symbol` according to the specified alignment `mode`, appending
+    /// the result to the `buffer`. The `buffer` is left unchanged on errors.
+    fn pad_to_buffer_utf16(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut String,
+    ) -> Result<(), SymbolWidthError>;
+}
+
+impl<S: Source<Symbol = char> + AsRef<str> + ?Sized> Utf16WidthSource for S {
+    fn pad_utf16(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<String, SymbolWidthError> {
+        let mut output = String::new();
+        self.pad_to_buffer_utf16(width, mode, symbol, &mut output)?;
+        Ok(output)
+    }
+
+    fn pad_to_buffer_utf16(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut String,
+    ) -> Result<(), SymbolWidthError> {
+        check_symbol(symbol)?;
+        pad_metric_to_sink(self.as_ref(), width, mode, symbol, &Utf16Width, buffer);
+        Ok(())
+    }
+}
+
+/// A companion trait to [`MutableSource`] which pads (or truncates) a string in-place to
+/// a width in UTF-16 code units, see [`Utf16WidthSource`].
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut s = String::from("🐉🐉🐉");
+/// (&mut s).pad_utf16(5, Alignment::Left, '.').unwrap();
+/// assert_eq!("🐉🐉.", s);
+/// assert_eq!(5, s.encode_utf16().count());
+/// ```
+pub trait MutableUtf16WidthSource: MutableSource<Symbol = char> {
+    /// Pads (or truncates) the string in-place to the specified `width` (in UTF-16 code units)
+    /// using the given single code unit `symbol` according to the specified alignment `mode`.
+    /// The string is left unchanged on errors.
+    fn pad_utf16(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<(), SymbolWidthError>;
+}
+
+impl MutableUtf16WidthSource for &mut String {
+    fn pad_utf16(
+        &mut self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<(), SymbolWidthError> {
+        check_symbol(symbol)?;
+        pad_metric_in_place(self, width, mode, symbol, &Utf16Width);
+        Ok(())
+    }
+}
+
+/// A companion trait to [`Source`] for UTF-16 encoded buffers (`&[u16]` and `Vec<u16>`), e.g.,
+/// as exchanged with JavaScript, Java or Windows APIs, which never splits a surrogate pair when
+/// truncating.
+///
+/// The generic [`Source`] impls of slices and vectors treat every `u16` as an opaque unit and
+/// may hence cut an astral char in half, whereas these methods fill the code unit which is left
+/// over with the pad `symbol` instead, such that the output is always exactly `width` code units
+/// long. The pad `symbol` has to be a single code unit, otherwise a [`SymbolWidthError`] is
+/// returned.
+///
+/// This is a separate trait rather than a surrogate-aware [`Source`] impl, as coherence forbids
+/// a second [`Source`] impl for `&[u16]` and `Vec<u16>` next to the generic ones.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let units: Vec<u16> = "ash🐉".encode_utf16().collect();
+/// assert_eq!(5, units.len());
+///
+/// let padded: Vec<u16> = units.pad_utf16(3, Alignment::Right, ' ').unwrap();
+/// assert_eq!("h🐉", String::from_utf16(&padded).unwrap());
+///
+/// // Keeping 4 code units from the left would split the '🐉', so the leftover unit is padded.
+/// let padded: Vec<u16> = units.pad_utf16(4, Alignment::Left, ' ').unwrap();
+/// assert_eq!("ash ", String::from_utf16(&padded).unwrap());
+/// ```
+pub trait Utf16Source: Source<Symbol = u16> {
+    /// Truncates the code units to at most the specified `width` according to the specified
+    /// alignment `mode`, never splitting a surrogate pair.
+    fn truncate_utf16(&self, width: usize, mode: Alignment) -> &[u16];
+
+    /// Pads (or truncates) the code units to the specified `width` using the given single code
+    /// unit `symbol` according to the specified alignment `mode`.
+    fn pad_utf16(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+    ) -> Result<Vec<u16>, SymbolWidthError>;
+
+    /// Pads (or truncates) the code units to the specified `width` using the given single code
+    /// unit `symbol` according to the specified alignment `mode`, appending the result to the
+    /// `buffer`. The `buffer` is left unchanged on errors.
+    fn pad_to_buffer_utf16(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut Vec<u16>,
+    ) -> Result<(), SymbolWidthError>;
+}
+
+/// Implements [`Utf16Source`] for a type which derefs to a `[u16]`.
+macro_rules! impl_utf16_source {
+    ($ty:ty) => {
+        impl Utf16Source for $ty {
+            fn truncate_utf16(&self, width: usize, mode: Alignment) -> &[u16] {
+                let (st_idx, ed_idx) = unit_range(self, width, mode);
+                &self[st_idx..ed_idx]
+            }
+
+            fn pad_utf16(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: char,
+            ) -> Result<Vec<u16>, SymbolWidthError> {
+                let mut output: Vec<u16> = Vec::new();
+                self.pad_to_buffer_utf16(width, mode, symbol, &mut output)?;
+                Ok(output)
+            }
+
+            fn pad_to_buffer_utf16(
+                &self,
+                width: impl Into<Width>,
+                mode: Alignment,
+                symbol: char,
+                buffer: &mut Vec<u16>,
+            ) -> Result<(), SymbolWidthError> {
+                check_symbol(symbol)?;
+                let width: usize = width.into().resolve(self.len());
+                let kept: &[u16] = self.truncate_utf16(width, mode);
+
+                let mut encoded = [0u16; 2];
+                let unit: u16 = symbol.encode_utf16(&mut encoded)[0];
+                let pads = mode.pads(width - kept.len());
+                buffer.reserve(width);
+                buffer.extend(std::iter::repeat_n(unit, pads.left()));
+                buffer.extend_from_slice(kept);
+                buffer.extend(std::iter::repeat_n(unit, pads.right()));
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_utf16_source!(&[u16]);
+impl_utf16_source!(Vec<u16>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn unit_range_never_splits_surrogates() {
+        let units: Vec<u16> = utf16("a🐉b");
+        assert_eq!((0, 1), unit_range(&units, 2, Alignment::Left));
+        assert_eq!((3, 4), unit_range(&units, 2, Alignment::Right));
+        assert_eq!((1, 3), unit_range(&units, 2, Alignment::Center));
+        assert_eq!((0, 0), unit_range(&utf16("🐉"), 1, Alignment::Center));
+        assert_eq!((0, 4), unit_range(&units, 7, Alignment::Left));
+    }
+
+    #[test]
+    fn unit_range_unpaired_surrogate() {
+        // A lone high surrogate followed by a regular char can be split anywhere.
+        let units: Vec<u16> = vec![0xD83D, 0x0061, 0x0062];
+        assert_eq!((1, 3), unit_range(&units, 2, Alignment::Right));
+    }
+
+    #[test]
+    fn pad_utf16_counts_code_units() {
+        let s = String::from("🐉ツ");
+        assert_eq!(
+            Ok(String::from("🐉ツ")),
+            s.pad_utf16(3, Alignment::Left, '-')
+        );
+        assert_eq!(
+            Ok(String::from("-🐉ツ-")),
+            s.pad_utf16(5, Alignment::Center, '-')
+        );
+        assert_eq!(
+            Ok(String::from("ツ-")),
+            "ツ🐉".pad_utf16(2, Alignment::Start, '-')
+        );
+        assert_eq!(
+            Ok(String::from("-ツ")),
+            "ツ🐉ツ".pad_utf16(2, Alignment::End, '-')
+        );
+        assert_eq!(
+            Ok(String::from("🐉ツ")),
+            s.pad_utf16(Width::AtLeast(2), Alignment::Right, '-')
+        );
+    }
+
+    #[test]
+    fn pad_to_buffer_utf16_rejects_astral_symbol() {
+        let mut buffer = String::from("|");
+        assert_eq!(
+            Err(SymbolWidthError {
+                symbol: '🐉',
+                width: 2
+            }),
+            "abc".pad_to_buffer_utf16(5, Alignment::Left, '🐉', &mut buffer)
+        );
+        assert_eq!("|", buffer);
+        "abc"
+            .pad_to_buffer_utf16(5, Alignment::Right, 'ツ', &mut buffer)
+            .unwrap();
+        assert_eq!("|ツツabc", buffer);
+    }
+
+    #[test]
+    fn mutable_pad_utf16() {
+        let mut s = String::from("a🐉");
+        (&mut s).pad_utf16(2, Alignment::Left, '_').unwrap();
+        assert_eq!("a_", s);
+
+        let mut s = String::from("ok");
+        assert!((&mut s).pad_utf16(4, Alignment::Left, '🦀').is_err());
+        assert_eq!("ok", s);
+    }
+
+    #[test]
+    fn utf16_source_slices() {
+        let units: Vec<u16> = utf16("🐉🐉x");
+        let slice: &[u16] = &units;
+        assert_eq!(&utf16("🐉")[..], slice.truncate_utf16(3, Alignment::Left));
+        assert_eq!(&utf16("🐉x")[..], units.truncate_utf16(4, Alignment::Right));
+        assert_eq!(Ok(utf16("🐉 ")), slice.pad_utf16(3, Alignment::Left, ' '));
+
+        let mut buffer: Vec<u16> = utf16("|");
+        units
+            .pad_to_buffer_utf16(Width::MultipleOf(4), Alignment::Right, '0', &mut buffer)
+            .unwrap();
+        assert_eq!(utf16("|000🐉🐉x"), buffer);
+    }
+}
@@ -0,0 +1,167 @@
+use std::io::{Cursor, Write};
+
+use crate::alignment::Alignment;
+use crate::sink::SinkSource;
+use crate::width::Width;
+
+/// A trait representing a growable buffer which UTF-8 encoded text can be appended to, e.g.,
+/// [`String`], `Vec<u8>`, or `bytes::BytesMut` (with the `bytes` feature).
+///
+/// This allows string sources to pad directly into the byte buffers used to build network
+/// responses, without first padding into a [`String`] and copying it.
+pub trait Utf8Buffer {
+    /// Reserves capacity for at least `additional` more bytes.
+    fn reserve_bytes(&mut self, additional: usize);
+
+    /// Appends the UTF-8 encoded `s` to the end of the buffer.
+    fn extend_utf8(&mut self, s: &str);
+
+    /// Appends the UTF-8 encoded `c` to the end of the buffer.
+    fn push_char(&mut self, c: char) {
+        let mut encoded = [0u8; 4];
+        self.extend_utf8(c.encode_utf8(&mut encoded));
+    }
+}
+
+impl Utf8Buffer for String {
+    fn reserve_bytes(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn extend_utf8(&mut self, s: &str) {
+        self.push_str(s);
+    }
+
+    fn push_char(&mut self, c: char) {
+        self.push(c);
+    }
+}
+
+impl Utf8Buffer for Vec<u8> {
+    fn reserve_bytes(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn extend_utf8(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes());
+    }
+}
+
+/// Writes at the current position of the cursor, overwriting any existing bytes and growing the
+/// underlying vector as needed.
+impl Utf8Buffer for Cursor<Vec<u8>> {
+    fn reserve_bytes(&mut self, additional: usize) {
+        self.get_mut().reserve(additional);
+    }
+
+    fn extend_utf8(&mut self, s: &str) {
+        self.write_all(s.as_bytes())
+            .expect("writing to a Cursor<Vec<u8>> should never fail");
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Utf8Buffer for bytes::BytesMut {
+    fn reserve_bytes(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn extend_utf8(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes());
+    }
+}
+
+/// A companion trait to [`crate::Source`] for string types which can pad into any [`Utf8Buffer`].
+///
+/// This is a thin wrapper around [`SinkSource::pad_to_sink`], as every [`Utf8Buffer`] is a
+/// [`crate::PadSink`] for strings.
+///
+/// [`crate::Source::Buffer`] is not generalized to cover these buffers: as an associated type
+/// it names a single buffer per source, which is what lets `pad_to_buffer` infer the buffer
+/// of `&mut String::new()` and what the slice sources rely on for `Vec<T>`. Padding into a
+/// caller-chosen buffer is the job of [`SinkSource`], this trait only narrows it to UTF-8.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// let mut response: Vec<u8> = Vec::from(b"HTTP/1.1 200 OK\r\nX-Player: ");
+/// "tarnished".pad_to_utf8_buffer(12, Alignment::Left, ' ', &mut response);
+/// assert_eq!(b"HTTP/1.1 200 OK\r\nX-Player: tarnished   ", response.as_slice());
+/// ```
+pub trait PadUtf8: SinkSource<Symbol = char, Content = str> {
+    /// Pads (or truncates) the string to the specified `width` (in utf8 chars) using the given
+    /// `symbol` according to the specified alignment `mode`, appending the UTF-8 encoded result
+    /// to the `buffer`.
+    fn pad_to_utf8_buffer<B: Utf8Buffer + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut B,
+    );
+}
+
+impl PadUtf8 for &str {
+    fn pad_to_utf8_buffer<B: Utf8Buffer + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut B,
+    ) {
+        self.pad_to_sink(width, mode, symbol, buffer);
+    }
+}
+
+impl PadUtf8 for String {
+    fn pad_to_utf8_buffer<B: Utf8Buffer + ?Sized>(
+        &self,
+        width: impl Into<Width>,
+        mode: Alignment,
+        symbol: char,
+        buffer: &mut B,
+    ) {
+        self.pad_to_sink(width, mode, symbol, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pad_to_string() {
+        let mut buffer = String::from("|");
+        "ツ".pad_to_utf8_buffer(3, Alignment::Center, '-', &mut buffer);
+        assert_eq!("|-ツ-", buffer);
+    }
+
+    #[test]
+    fn pad_to_vec_multibyte_symbol() {
+        let mut buffer: Vec<u8> = Vec::new();
+        String::from("ds").pad_to_utf8_buffer(3, Alignment::Right, '🔥', &mut buffer);
+        assert_eq!("🔥ds".as_bytes(), buffer.as_slice());
+    }
+
+    #[test]
+    fn pad_to_vec_truncates() {
+        let mut buffer: Vec<u8> = Vec::new();
+        "こんにちは".pad_to_utf8_buffer(Width::AtMost(2), Alignment::Left, ' ', &mut buffer);
+        assert_eq!("こん".as_bytes(), buffer.as_slice());
+    }
+}
+
+#[cfg(all(test, feature = "bytes"))]
+mod tests_bytes {
+    use super::*;
+    use bytes::BytesMut;
+
+    #[test]
+    fn pad_to_bytes_mut() {
+        let mut buffer = BytesMut::with_capacity(16);
+        "gael".pad_to_utf8_buffer(6, Alignment::Right, '0', &mut buffer);
+        "slave".pad_to_utf8_buffer(6, Alignment::Left, '.', &mut buffer);
+        assert_eq!(&b"00gaelslave."[..], &buffer[..]);
+    }
+}
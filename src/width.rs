@@ -0,0 +1,91 @@
+/// Specifies the strategy used to determine the target width of a buffer when padding it.
+///
+/// - `Exact(n)`: pads or truncates the buffer to exactly `n` units.
+/// - `AtLeast(n)`: pads the buffer to `n` units if it is shorter, but never truncates it.
+/// - `AtMost(n)`: truncates the buffer to `n` units if it is longer, but never pads it.
+/// - `MultipleOf(n)`: pads the buffer to the next multiple of `n` units, e.g., to align
+///   sections of a file or SIMD lanes (`MultipleOf(0)` leaves the buffer unchanged).
+///
+/// A plain `usize` converts into `Exact`, so every method accepting a `width` works with both.
+/// Integer literals such as `pad(10, ...)` only infer as `usize` because it is the single
+/// integer type implementing `Into<Width>`; adding another integer conversion would break them.
+///
+/// # Examples
+/// ```
+/// use padder::*;
+///
+/// assert_eq!(4, Width::Exact(4).resolve(10));
+/// assert_eq!(10, Width::AtLeast(4).resolve(10));
+/// assert_eq!(4, Width::AtMost(4).resolve(10));
+/// assert_eq!(16, Width::MultipleOf(8).resolve(10));
+///
+/// let v: Vec<u8> = Vec::from(&[1u8, 2, 3, 4, 5]);
+/// assert_eq!(Vec::from(&[1u8, 2, 3, 4, 5, 0, 0, 0]), v.pad(Width::MultipleOf(4), Alignment::Left, 0u8));
+/// assert_eq!(Vec::from(&[0u8, 1, 2, 3, 4, 5]), v.pad(Width::AtLeast(6), Alignment::Right, 0u8));
+/// assert_eq!(v, v.pad(Width::AtLeast(3), Alignment::Right, 0u8));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Width {
+    Exact(usize),
+    AtLeast(usize),
+    AtMost(usize),
+    MultipleOf(usize),
+}
+
+impl Width {
+    /// Returns the target width for a buffer which currently is `len` units wide.
+    pub fn resolve(&self, len: usize) -> usize {
+        match *self {
+            Self::Exact(n) => n,
+            Self::AtLeast(n) => len.max(n),
+            Self::AtMost(n) => len.min(n),
+            Self::MultipleOf(0) => len,
+            Self::MultipleOf(n) => len.div_ceil(n) * n,
+        }
+    }
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Exact(0)
+    }
+}
+
+// Do not add `From` impls for other integer types, see the type level docs.
+impl From<usize> for Width {
+    fn from(width: usize) -> Self {
+        Self::Exact(width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn width_exact() {
+        assert_eq!(3, Width::Exact(3).resolve(9));
+        assert_eq!(3, Width::from(3).resolve(1));
+    }
+
+    #[test]
+    fn width_at_least() {
+        assert_eq!(9, Width::AtLeast(3).resolve(9));
+        assert_eq!(3, Width::AtLeast(3).resolve(1));
+    }
+
+    #[test]
+    fn width_at_most() {
+        assert_eq!(3, Width::AtMost(3).resolve(9));
+        assert_eq!(1, Width::AtMost(3).resolve(1));
+    }
+
+    #[test]
+    fn width_multiple_of() {
+        assert_eq!(4096, Width::MultipleOf(4096).resolve(1));
+        assert_eq!(16, Width::MultipleOf(16).resolve(16));
+        assert_eq!(32, Width::MultipleOf(16).resolve(17));
+        assert_eq!(0, Width::MultipleOf(8).resolve(0));
+        assert_eq!(7, Width::MultipleOf(0).resolve(7));
+    }
+}